@@ -1,10 +1,114 @@
-//! Contains vector definitions with a fixed bit width, reexported from [wide](https://crates.io/crates/wide)
+//! Contains vector definitions with a fixed bit width, reexported from [wide](https://crates.io/crates/wide).
+//!
+//! Native aliases (e.g., [`f32s`]) pick the widest vector for the current build target. They are
+//! selected at compile time: with `avx2` they are 256 bit wide, otherwise (e.g., `sse2`, `neon`,
+//! `simd128`, or no SIMD support at all) they are 128 bit wide. Enable wider vectors with
+//! `RUSTFLAGS="-C target-cpu=native"` or `-C target-feature=+avx2`.
 #![allow(non_camel_case_types)]
 
 pub use wide::{f32x4, f32x8, f64x2, f64x4, i16x16, i16x8, i32x4, i32x8, i64x2, i64x4, i8x16, i8x32, u16x16, u16x8, u32x4, u32x8, u64x2, u64x4, u8x16};
 
+/// Scalar operations on single lanes, with the same semantics as the lane-wise SIMD operations.
+trait Lane: Copy {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn add(self, other: Self) -> Self;
+    fn mul(self, other: Self) -> Self;
+    fn min(self, other: Self) -> Self;
+    fn max(self, other: Self) -> Self;
+}
+
+macro_rules! impl_lane_int {
+    ($($element:ty),*) => {
+        $(
+            impl Lane for $element {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn add(self, other: Self) -> Self {
+                    self.wrapping_add(other)
+                }
+
+                fn mul(self, other: Self) -> Self {
+                    self.wrapping_mul(other)
+                }
+
+                fn min(self, other: Self) -> Self {
+                    Ord::min(self, other)
+                }
+
+                fn max(self, other: Self) -> Self {
+                    Ord::max(self, other)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_lane_float {
+    ($($element:ty),*) => {
+        $(
+            impl Lane for $element {
+                const ZERO: Self = 0.0;
+                const ONE: Self = 1.0;
+
+                fn add(self, other: Self) -> Self {
+                    self + other
+                }
+
+                fn mul(self, other: Self) -> Self {
+                    self * other
+                }
+
+                fn min(self, other: Self) -> Self {
+                    <$element>::min(self, other)
+                }
+
+                fn max(self, other: Self) -> Self {
+                    <$element>::max(self, other)
+                }
+            }
+        )*
+    };
+}
+
 macro_rules! impl_simd {
+    // Types with native lane-wise `min` and `max`.
     ($simd:ty, $element:ty, $lanes:expr, $lanestype:ty) => {
+        impl_simd!(@impl $simd, $element, $lanes, $lanestype, {
+            fn min(self, other: Self) -> Self {
+                <$simd>::min(self, other)
+            }
+
+            fn max(self, other: Self) -> Self {
+                <$simd>::max(self, other)
+            }
+        });
+    };
+
+    // Types without, which compute them lane by lane.
+    ($simd:ty, $element:ty, $lanes:expr, $lanestype:ty, lanewise) => {
+        impl_simd!(@impl $simd, $element, $lanes, $lanestype, {
+            fn min(mut self, other: Self) -> Self {
+                for (a, b) in self.as_array_mut().iter_mut().zip(other.as_array()) {
+                    *a = Lane::min(*a, *b);
+                }
+
+                self
+            }
+
+            fn max(mut self, other: Self) -> Self {
+                for (a, b) in self.as_array_mut().iter_mut().zip(other.as_array()) {
+                    *a = Lane::max(*a, *b);
+                }
+
+                self
+            }
+        });
+    };
+
+    (@impl $simd:ty, $element:ty, $lanes:expr, $lanestype:ty, { $($min_max:tt)* }) => {
         impl crate::traits::Simd for $simd {
             type Element = $element;
             type LanesType = $lanestype;
@@ -15,6 +119,11 @@ macro_rules! impl_simd {
                 Self::splat(t)
             }
 
+            #[inline]
+            fn from_array(array: Self::LanesType) -> Self {
+                Self::from(array)
+            }
+
             #[allow(clippy::transmute_ptr_to_ptr)]
             #[allow(clippy::missing_transmute_annotations)]
             fn as_array(&self) -> &[Self::Element] {
@@ -22,13 +131,98 @@ macro_rules! impl_simd {
                 self_array.as_ref()
             }
 
+            #[allow(clippy::transmute_ptr_to_ptr)]
+            #[allow(clippy::missing_transmute_annotations)]
+            fn as_array_mut(&mut self) -> &mut [Self::Element] {
+                let self_array = unsafe { std::mem::transmute::<_, &mut $lanestype>(self) };
+                self_array.as_mut()
+            }
+
+            $($min_max)*
+
             fn sum(&self) -> Self::Element {
-                self.as_array().iter().sum()
+                self.as_array().iter().fold(Lane::ZERO, |a, b| Lane::add(a, *b))
+            }
+
+            fn reduce_product(&self) -> Self::Element {
+                self.as_array().iter().fold(Lane::ONE, |a, b| Lane::mul(a, *b))
+            }
+
+            fn reduce_min(&self) -> Self::Element {
+                let (first, rest) = self.as_array().split_first().expect("SIMD vectors have lanes");
+                rest.iter().fold(*first, |a, b| Lane::min(a, *b))
+            }
+
+            fn reduce_max(&self) -> Self::Element {
+                let (first, rest) = self.as_array().split_first().expect("SIMD vectors have lanes");
+                rest.iter().fold(*first, |a, b| Lane::max(a, *b))
+            }
+        }
+
+        // Fails to compile if `ops` lacks scalar operators for the element type.
+        const _: fn() = || {
+            const fn scalar_ops<V: std::ops::AddAssign<E> + std::ops::SubAssign<E>, E>() {}
+            scalar_ops::<crate::VecSimd<$simd>, $element>();
+        };
+    };
+}
+
+macro_rules! impl_native {
+    ($($alias:ident, $element:ty, $feature:literal => $wide:ty, $narrow:ty;)*) => {
+        $(
+            #[doc = concat!("Widest available SIMD vector of `", stringify!($element), "`, `", stringify!($wide), "` with `", $feature, "`.")]
+            #[cfg(target_feature = $feature)]
+            pub type $alias = $wide;
+
+            #[doc = concat!("Widest available SIMD vector of `", stringify!($element), "`, `", stringify!($narrow), "` with 128 bit SIMD but without `", $feature, "`.")]
+            #[cfg(all(not(target_feature = $feature), any(target_feature = "sse2", target_feature = "neon", target_feature = "simd128")))]
+            pub type $alias = $narrow;
+
+            #[doc = concat!("Widest available SIMD vector of `", stringify!($element), "`, `", stringify!($narrow), "` without SIMD support.")]
+            ///
+            /// `wide` then emulates vectors with arrays, so the narrowest vector is used.
+            #[cfg(not(any(target_feature = $feature, target_feature = "sse2", target_feature = "neon", target_feature = "simd128")))]
+            pub type $alias = $narrow;
+        )*
+    };
+}
+
+macro_rules! impl_float {
+    ($element:ty) => {
+        impl crate::traits::Float for $element {
+            #[inline]
+            fn abs(self) -> Self {
+                <$element>::abs(self)
+            }
+
+            #[inline]
+            fn sqrt(self) -> Self {
+                <$element>::sqrt(self)
+            }
+
+            #[inline]
+            #[allow(clippy::cast_precision_loss)]
+            fn from_usize(x: usize) -> Self {
+                x as $element
             }
         }
     };
 }
 
+macro_rules! impl_simd_float {
+    ($simd:ty) => {
+        impl crate::traits::SimdFloat for $simd {
+            #[inline]
+            fn abs(self) -> Self {
+                <$simd>::abs(self)
+            }
+        }
+    };
+}
+
+impl_lane_int!(u8, i8, u16, i16, u32, i32, u64, i64);
+impl_lane_float!(f32, f64);
+
 impl_simd!(u8x16, u8, 16, [u8; 16]);
 
 impl_simd!(i8x16, i8, 16, [i8; 16]);
@@ -46,14 +240,113 @@ impl_simd!(u32x8, u32, 8, [u32; 8]);
 impl_simd!(i32x4, i32, 4, [i32; 4]);
 impl_simd!(i32x8, i32, 8, [i32; 8]);
 
-impl_simd!(u64x2, u64, 2, [u64; 2]);
-impl_simd!(u64x4, u64, 4, [u64; 4]);
+impl_simd!(u64x2, u64, 2, [u64; 2], lanewise);
+impl_simd!(u64x4, u64, 4, [u64; 4], lanewise);
 
-impl_simd!(i64x2, i64, 2, [i64; 2]);
-impl_simd!(i64x4, i64, 4, [i64; 4]);
+impl_simd!(i64x2, i64, 2, [i64; 2], lanewise);
+impl_simd!(i64x4, i64, 4, [i64; 4], lanewise);
 
 impl_simd!(f32x4, f32, 4, [f32; 4]);
 impl_simd!(f32x8, f32, 8, [f32; 8]);
 
 impl_simd!(f64x2, f64, 2, [f64; 2]);
 impl_simd!(f64x4, f64, 4, [f64; 4]);
+
+// `wide` has 256 bit integer vectors with `avx2`, but 256 bit float vectors already with `avx`.
+impl_native! {
+    i8s, i8, "avx2" => i8x32, i8x16;
+    u16s, u16, "avx2" => u16x16, u16x8;
+    i16s, i16, "avx2" => i16x16, i16x8;
+    u32s, u32, "avx2" => u32x8, u32x4;
+    i32s, i32, "avx2" => i32x8, i32x4;
+    u64s, u64, "avx2" => u64x4, u64x2;
+    i64s, i64, "avx2" => i64x4, i64x2;
+    f32s, f32, "avx" => f32x8, f32x4;
+    f64s, f64, "avx" => f64x4, f64x2;
+}
+
+/// Widest available SIMD vector of `u8`, always `u8x16`.
+///
+/// `wide` 0.7 has no `u8x32`, so there is no wider vector to pick with `avx2`.
+pub type u8s = u8x16;
+
+impl_float!(f32);
+impl_float!(f64);
+
+impl_simd_float!(f32x4);
+impl_simd_float!(f32x8);
+impl_simd_float!(f64x2);
+impl_simd_float!(f64x4);
+
+#[cfg(test)]
+mod test {
+    use super::{f32s, f32x4, f64s, i16s, i32s, i64s, i64x4, i8s, u16s, u32s, u64s, u8s, u8x16};
+    use crate::{traits::Simd, VecSimd};
+
+    #[test]
+    fn native_lanes() {
+        let simd128 = cfg!(any(target_feature = "sse2", target_feature = "neon", target_feature = "simd128"));
+
+        #[allow(clippy::match_same_arms)]
+        let (int_bytes, float_bytes) = match (cfg!(target_feature = "avx2"), cfg!(target_feature = "avx"), simd128) {
+            (true, _, _) => (32, 32),
+            (false, true, _) => (16, 32),
+            (false, false, true) => (16, 16),
+            // Emulated by `wide`, so the narrowest vectors are used.
+            (false, false, false) => (16, 16),
+        };
+
+        assert_eq!(<i8s as Simd>::LANES, int_bytes);
+        assert_eq!(<u16s as Simd>::LANES, int_bytes / 2);
+        assert_eq!(<i16s as Simd>::LANES, int_bytes / 2);
+        assert_eq!(<u32s as Simd>::LANES, int_bytes / 4);
+        assert_eq!(<i32s as Simd>::LANES, int_bytes / 4);
+        assert_eq!(<u64s as Simd>::LANES, int_bytes / 8);
+        assert_eq!(<i64s as Simd>::LANES, int_bytes / 8);
+        assert_eq!(<f32s as Simd>::LANES, float_bytes / 4);
+        assert_eq!(<f64s as Simd>::LANES, float_bytes / 8);
+        assert_eq!(<u8s as Simd>::LANES, 16);
+    }
+
+    #[test]
+    fn native_vec() {
+        let v = VecSimd::<f32s>::with(1.0, 10);
+
+        assert_eq!(v.len(), 10_usize.div_ceil(<f32s as Simd>::LANES));
+        assert_eq!(std::mem::size_of::<f32s>(), <f32s as Simd>::LANES * 4);
+    }
+
+    fn roundtrip<T: Simd>(values: &[T::Element]) -> T {
+        let mut x = T::from_slice(values);
+        let mut out = vec![T::Element::default(); T::LANES + 1];
+
+        x.write_to_slice(&mut out);
+        assert_eq!(&out[..T::LANES], &values[..T::LANES]);
+
+        x.as_array_mut()[0] = values[1];
+        x
+    }
+
+    #[test]
+    fn simd_trait() {
+        let x = roundtrip::<i64x4>(&[3, -7, 2, 5, 100]);
+
+        assert_eq!(x.as_array(), &[-7, -7, 2, 5]);
+        assert_eq!(x.min(i64x4::from_array([0, 0, 1, 9])).as_array(), &[-7, -7, 1, 5]);
+        assert_eq!(x.max(i64x4::splat(1)).as_array(), &[1, 1, 2, 5]);
+        assert_eq!((x.sum(), x.reduce_product(), x.reduce_min(), x.reduce_max()), (-7, 490, -7, 5));
+
+        let y = roundtrip::<u8x16>(&[200; 17]);
+
+        assert_eq!(y.sum(), 128);
+        assert_eq!(y.reduce_product(), 0);
+        assert_eq!(y.min(u8x16::splat(3)).reduce_max(), 3);
+
+        let mut z = roundtrip::<f32x4>(&[1.0, -2.0, 4.0, 0.5]);
+        z.as_array_mut()[0] = f32::NAN;
+
+        assert_eq!(z.reduce_min().to_bits(), (-2.0_f32).to_bits());
+        assert_eq!(z.reduce_max().to_bits(), 4.0_f32.to_bits());
+        assert_eq!(z.max(f32x4::splat(0.0)).reduce_min().to_bits(), 0.0_f32.to_bits());
+    }
+}
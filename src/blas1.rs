@@ -0,0 +1,166 @@
+//! BLAS level 1 style kernels on [`VecSimd`] with floating point lanes.
+//!
+//! All functions operate on the flat view of their arguments and handle the partial last
+//! SIMD vector correctly, no matter what the [`Padding`](crate::Padding) lanes contain.
+//!
+//! # Accuracy
+//!
+//! Reductions ([`dot`], [`nrm2`], [`asum`]) accumulate `T::LANES` partial sums in parallel
+//! instead of summing strictly left to right. For `n` elements the result differs from a
+//! sequential scalar reference by at most about `n * EPSILON * sum(|x_i * y_i|)` (or
+//! `sum(|x_i|)` respectively), which in practice is several orders of magnitude smaller.
+//! The accumulation order is fixed, so results are reproducible between runs and identical
+//! with and without the `rayon` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! use simd_aligned::{blas1, VecSimd, arch::f32x4};
+//!
+//! let x = VecSimd::<f32x4>::from_slice(&[1.0, 2.0, 3.0]);
+//! let mut y = VecSimd::<f32x4>::from_slice(&[1.0, 1.0, 1.0]);
+//!
+//! blas1::axpy(2.0, &x, &mut y);
+//!
+//! assert_eq!(y.flat(), &[3.0, 5.0, 7.0]);
+//! assert_eq!(blas1::dot(&x, &y), 34.0);
+//! ```
+use crate::{
+    dispatch, kernels,
+    traits::{Float, MaybeSendSync, SimdFloat},
+    VecSimd,
+};
+
+#[inline]
+fn assert_same_length<T: SimdFloat>(x: &VecSimd<T>, y: &VecSimd<T>) {
+    assert_eq!(
+        x.simd_rows.row_length, y.simd_rows.row_length,
+        "VecSimd length mismatch: left has {} elements, right has {}",
+        x.simd_rows.row_length, y.simd_rows.row_length
+    );
+}
+
+/// Computes the dot product `sum(x_i * y_i)`.
+///
+/// # Panics
+///
+/// Panics if `x` and `y` differ in length.
+#[inline]
+#[must_use]
+pub fn dot<T: SimdFloat + MaybeSendSync>(x: &VecSimd<T>, y: &VecSimd<T>) -> T::Element {
+    assert_same_length(x, y);
+    kernels::dot(dispatch::level(), &x.simd_rows.data, &y.simd_rows.data, x.simd_rows.row_length)
+}
+
+/// Computes `y = alpha * x + y`.
+///
+/// # Panics
+///
+/// Panics if `x` and `y` differ in length.
+#[inline]
+pub fn axpy<T: SimdFloat + MaybeSendSync>(alpha: T::Element, x: &VecSimd<T>, y: &mut VecSimd<T>) {
+    assert_same_length(x, y);
+    kernels::axpy(dispatch::level(), alpha, &x.simd_rows.data, &mut y.simd_rows.data);
+    y.simd_rows.reset_padding();
+}
+
+/// Computes `x = alpha * x`.
+#[inline]
+pub fn scal<T: SimdFloat + MaybeSendSync>(alpha: T::Element, x: &mut VecSimd<T>) {
+    kernels::scal(dispatch::level(), alpha, &mut x.simd_rows.data);
+    x.simd_rows.reset_padding();
+}
+
+/// Computes the Euclidean norm `sqrt(sum(x_i^2))`.
+///
+/// No scaling is applied, so the result overflows if `sum(x_i^2)` exceeds the range of `T::Element`.
+#[inline]
+#[must_use]
+pub fn nrm2<T: SimdFloat + MaybeSendSync>(x: &VecSimd<T>) -> T::Element {
+    kernels::dot(dispatch::level(), &x.simd_rows.data, &x.simd_rows.data, x.simd_rows.row_length).sqrt()
+}
+
+/// Computes the sum of absolute values `sum(|x_i|)`.
+#[inline]
+#[must_use]
+pub fn asum<T: SimdFloat + MaybeSendSync>(x: &VecSimd<T>) -> T::Element {
+    kernels::asum(dispatch::level(), &x.simd_rows.data, x.simd_rows.row_length)
+}
+
+/// Returns the index of the first element with the largest absolute value, or `None` if `x` is empty.
+#[inline]
+#[must_use]
+pub fn iamax<T: SimdFloat>(x: &VecSimd<T>) -> Option<usize> {
+    kernels::iamax(&x.simd_rows.data, x.simd_rows.row_length)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{asum, axpy, dot, iamax, nrm2, scal};
+    use crate::{
+        arch::{f32x8, f64x4},
+        packed_as_flat, Padding, VecSimd,
+    };
+
+    fn data(n: usize) -> Vec<f64> {
+        (0..n).map(|i| f64::from(u8::try_from(i * 7919 % 113).unwrap()) / 17.0 - 3.0).collect()
+    }
+
+    fn close(a: f64, b: f64, tolerance: f64) -> bool {
+        (a - b).abs() <= tolerance * b.abs().max(1.0)
+    }
+
+    #[test]
+    fn against_scalar_f64() {
+        for n in [0, 1, 3, 4, 5, 31, 1000] {
+            let x_flat = data(n);
+            let y_flat = data(n + 3)[3..].to_vec();
+
+            let mut x = VecSimd::<f64x4>::from_slice(&x_flat);
+            let y = VecSimd::<f64x4>::from_slice(&y_flat);
+
+            x.set_padding(Padding::Value(f64::NAN));
+
+            let dot_ref = x_flat.iter().zip(&y_flat).map(|(a, b)| a * b).sum::<f64>();
+            let asum_ref = x_flat.iter().map(|a| a.abs()).sum::<f64>();
+            let nrm2_ref = x_flat.iter().map(|a| a * a).sum::<f64>().sqrt();
+
+            assert!(close(dot(&x, &y), dot_ref, 1e-12));
+            assert!(close(asum(&x), asum_ref, 1e-12));
+            assert!(close(nrm2(&x), nrm2_ref, 1e-12));
+        }
+    }
+
+    #[test]
+    fn against_scalar_f32() {
+        let x_flat = (0..1001_u16).map(|i| f32::from(i % 113) / 17.0 - 3.0).collect::<Vec<_>>();
+        let x = VecSimd::<f32x8>::from_slice(&x_flat);
+
+        let dot_ref = x_flat.iter().map(|a| f64::from(*a) * f64::from(*a)).sum::<f64>();
+        let asum_ref = x_flat.iter().map(|a| f64::from(a.abs())).sum::<f64>();
+
+        assert!(close(f64::from(dot(&x, &x)), dot_ref, 1e-5));
+        assert!(close(f64::from(asum(&x)), asum_ref, 1e-5));
+
+        let max = x_flat.iter().fold(0.0_f32, |a, b| a.max(b.abs()));
+        assert_eq!(iamax(&x), x_flat.iter().position(|a| a.abs() >= max));
+    }
+
+    #[test]
+    fn axpy_scal() {
+        let x = VecSimd::<f64x4>::from_slice(&[1.0, 2.0, 3.0, 4.0, 5.0]);
+        let mut y = VecSimd::<f64x4>::from_slice(&[1.0, 1.0, 1.0, 1.0, 1.0]);
+
+        y.set_padding(Padding::Value(-1.0));
+
+        axpy(2.0, &x, &mut y);
+        assert_eq!(y.flat(), &[3.0, 5.0, 7.0, 9.0, 11.0]);
+
+        scal(0.5, &mut y);
+        assert_eq!(y.flat(), &[1.5, 2.5, 3.5, 4.5, 5.5]);
+        assert_eq!(&packed_as_flat(&y)[4..], &[5.5, -1.0, -1.0, -1.0]);
+
+        assert_eq!(iamax(&y), Some(4));
+        assert_eq!(iamax(&VecSimd::<f64x4>::with(0.0, 0)), None);
+    }
+}
@@ -0,0 +1,551 @@
+use std::{
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+use crate::{
+    chunks::SimdChunks,
+    conversion::{
+        packed_row, packed_row_as_flat, packed_row_as_flat_mut, packed_row_mut, packed_vectors_per_row, simd_container_flat_slice, simd_container_flat_slice_mut,
+        strategy_vectors_per_row,
+    },
+    traits::Simd,
+    AccessStrategy, Columns, Error, MatFlat, MatFlatMut, MatSimd, Matrix2DFlatIter, Matrix2DFlatIterMut, Matrix2DIter, Matrix2DIterMut, Rows, VecSimd,
+};
+
+/// A [`VecSimd`] over borrowed SIMD vectors, e.g., from an arena.
+///
+/// Padding lanes are whatever the storage holds, no [`Padding`](crate::Padding) policy is applied.
+///
+/// # Example
+///
+/// ```rust
+/// use simd_aligned::{VecSimdRef, arch::f32x4};
+///
+/// let storage = [f32x4::from([1.0, 2.0, 3.0, 4.0]), f32x4::from([5.0, 0.0, 0.0, 0.0])];
+/// let v = VecSimdRef::new(&storage, 5)?;
+///
+/// assert_eq!(v.flat(), &[1.0, 2.0, 3.0, 4.0, 5.0]);
+/// # Ok::<(), simd_aligned::Error>(())
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct VecSimdRef<'a, T>
+where
+    T: Simd,
+{
+    data: &'a [T],
+    length: usize,
+}
+
+/// A [`VecSimd`] over borrowed SIMD vectors, allowing modification.
+#[derive(Debug)]
+pub struct VecSimdMut<'a, T>
+where
+    T: Simd,
+{
+    data: &'a mut [T],
+    length: usize,
+}
+
+impl<'a, T> VecSimdRef<'a, T>
+where
+    T: Simd,
+{
+    /// Views `data` as a vector of `length` elements.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DimensionMismatch`] if `data` doesn't hold exactly the vectors needed for `length` elements.
+    #[inline]
+    pub fn new(data: &'a [T], length: usize) -> Result<Self, Error> {
+        packed_vectors_per_row::<T>(data.len(), 1, length)?;
+
+        Ok(Self { data, length })
+    }
+
+    /// Returns the number of elements.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns `true` if there are no elements.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns a flat view of all elements.
+    #[inline]
+    #[must_use]
+    pub const fn flat(&self) -> &'a [T::Element] {
+        simd_container_flat_slice(self.data, self.length)
+    }
+
+    /// Returns an iterator over all SIMD vectors, yielding the partial last vector as [`SimdChunk::Tail`](crate::SimdChunk::Tail).
+    #[inline]
+    #[must_use]
+    pub const fn simd_chunks(&self) -> SimdChunks<'a, T> {
+        SimdChunks::new(self.data, self.length)
+    }
+}
+
+impl<'a, T> VecSimdMut<'a, T>
+where
+    T: Simd,
+{
+    /// Views `data` as a vector of `length` elements.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DimensionMismatch`] if `data` doesn't hold exactly the vectors needed for `length` elements.
+    #[inline]
+    pub fn new(data: &'a mut [T], length: usize) -> Result<Self, Error> {
+        packed_vectors_per_row::<T>(data.len(), 1, length)?;
+
+        Ok(Self { data, length })
+    }
+
+    /// Returns the number of elements.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns `true` if there are no elements.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns an immutable view of this vector.
+    #[inline]
+    #[must_use]
+    pub const fn as_simd_ref(&self) -> VecSimdRef<'_, T> {
+        VecSimdRef {
+            data: self.data,
+            length: self.length,
+        }
+    }
+
+    /// Returns a flat view of all elements.
+    #[inline]
+    #[must_use]
+    pub const fn flat(&self) -> &[T::Element] {
+        simd_container_flat_slice(self.data, self.length)
+    }
+
+    /// Returns a flat, mutable view of all elements.
+    #[inline]
+    pub const fn flat_mut(&mut self) -> &mut [T::Element] {
+        simd_container_flat_slice_mut(self.data, self.length)
+    }
+}
+
+impl<T> Deref for VecSimdRef<'_, T>
+where
+    T: Simd,
+{
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        self.data
+    }
+}
+
+impl<T> Deref for VecSimdMut<'_, T>
+where
+    T: Simd,
+{
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        self.data
+    }
+}
+
+impl<T> DerefMut for VecSimdMut<'_, T>
+where
+    T: Simd,
+{
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.data
+    }
+}
+
+/// A [`MatSimd`] over borrowed SIMD vectors, e.g., from an arena.
+///
+/// The vectors are laid out as in [`MatSimd`], i.e., one packed row after another, where packed
+/// rows are rows for [`Rows`] and columns for [`Columns`]. Padding lanes are whatever the storage
+/// holds, no [`Padding`](crate::Padding) policy is applied.
+///
+/// # Example
+///
+/// ```rust
+/// use simd_aligned::{MatSimdRef, arch::f32x4, Rows};
+///
+/// let storage = [f32x4::splat(1.0), f32x4::splat(2.0), f32x4::splat(3.0)];
+/// let m = MatSimdRef::<_, Rows>::new(&storage, 3, 4)?;
+///
+/// assert_eq!(m.row_as_flat(2), &[3.0; 4]);
+/// assert_eq!(m.flat()[(1, 3)], 2.0);
+/// # Ok::<(), simd_aligned::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct MatSimdRef<'a, T, A>
+where
+    T: Simd,
+    A: AccessStrategy,
+{
+    data: &'a [T],
+    rows: usize,
+    row_length: usize,
+    vectors_per_row: usize,
+    phantom: PhantomData<A>,
+}
+
+/// A [`MatSimd`] over borrowed SIMD vectors, allowing modification.
+#[derive(Debug)]
+pub struct MatSimdMut<'a, T, A>
+where
+    T: Simd,
+    A: AccessStrategy,
+{
+    data: &'a mut [T],
+    rows: usize,
+    row_length: usize,
+    vectors_per_row: usize,
+    phantom: PhantomData<A>,
+}
+
+impl<T, A> Clone for MatSimdRef<'_, T, A>
+where
+    T: Simd,
+    A: AccessStrategy,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, A> Copy for MatSimdRef<'_, T, A>
+where
+    T: Simd,
+    A: AccessStrategy,
+{
+}
+
+impl<'a, T, A> MatSimdRef<'a, T, A>
+where
+    T: Simd,
+    A: AccessStrategy,
+{
+    /// Views `data` as a matrix with the given dimension.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DimensionMismatch`] if `data` doesn't hold exactly the vectors needed for the dimension.
+    #[inline]
+    pub fn new(data: &'a [T], rows: usize, columns: usize) -> Result<Self, Error> {
+        let (rows, row_length) = A::flat_to_packed(rows, columns);
+        let vectors_per_row = strategy_vectors_per_row::<T, A>(data.len(), rows, row_length)?;
+
+        Ok(Self::from_parts(data, rows, row_length, vectors_per_row))
+    }
+
+    /// Wraps packed rows that are known to fit.
+    #[inline]
+    pub(crate) const fn from_parts(data: &'a [T], rows: usize, row_length: usize, vectors_per_row: usize) -> Self {
+        Self {
+            data,
+            rows,
+            row_length,
+            vectors_per_row,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns all SIMD vectors, including padding.
+    #[inline]
+    pub(crate) const fn data(&self) -> &'a [T] {
+        self.data
+    }
+
+    /// Returns the size as (`rows`, `columns`).
+    #[inline]
+    #[must_use]
+    pub fn dimension(&self) -> (usize, usize) {
+        A::packed_to_flat(self.rows, self.row_length)
+    }
+
+    /// Provides a flat, immutable view of the contained data.
+    #[inline]
+    #[must_use]
+    pub const fn flat(&self) -> MatFlat<'a, T, A> {
+        MatFlat::new(self.data, self.rows, self.vectors_per_row, self.row_length)
+    }
+}
+
+impl<'a, T, A> MatSimdMut<'a, T, A>
+where
+    T: Simd,
+    A: AccessStrategy,
+{
+    /// Views `data` as a matrix with the given dimension.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DimensionMismatch`] if `data` doesn't hold exactly the vectors needed for the dimension.
+    #[inline]
+    pub fn new(data: &'a mut [T], rows: usize, columns: usize) -> Result<Self, Error> {
+        let (rows, row_length) = A::flat_to_packed(rows, columns);
+        let vectors_per_row = strategy_vectors_per_row::<T, A>(data.len(), rows, row_length)?;
+
+        Ok(Self {
+            data,
+            rows,
+            row_length,
+            vectors_per_row,
+            phantom: PhantomData,
+        })
+    }
+
+    /// Returns the size as (`rows`, `columns`).
+    #[inline]
+    #[must_use]
+    pub fn dimension(&self) -> (usize, usize) {
+        A::packed_to_flat(self.rows, self.row_length)
+    }
+
+    /// Returns an immutable view of this matrix.
+    #[inline]
+    #[must_use]
+    pub const fn as_simd_ref(&self) -> MatSimdRef<'_, T, A> {
+        MatSimdRef::from_parts(self.data, self.rows, self.row_length, self.vectors_per_row)
+    }
+
+    /// Provides a flat, immutable view of the contained data.
+    #[inline]
+    #[must_use]
+    pub const fn flat(&self) -> MatFlat<'_, T, A> {
+        MatFlat::new(self.data, self.rows, self.vectors_per_row, self.row_length)
+    }
+
+    /// Provides a flat mutable view of the contained data.
+    #[inline]
+    pub const fn flat_mut(&mut self) -> MatFlatMut<'_, T, A> {
+        MatFlatMut::new(self.data, self.rows, self.vectors_per_row, self.row_length)
+    }
+}
+
+macro_rules! impl_borrowed_mat {
+    ($strategy:ty, $row:ident, $row_mut:ident, $row_as_flat:ident, $row_as_flat_mut:ident, $row_iter:ident, $row_iter_mut:ident, $row_iter_as_flat:ident, $row_iter_as_flat_mut:ident) => {
+        impl<'a, T> MatSimdRef<'a, T, $strategy>
+        where
+            T: Simd,
+        {
+            #[inline]
+            #[must_use]
+            pub fn $row(&self, i: usize) -> &'a [T] {
+                packed_row(self.data, self.vectors_per_row, i)
+            }
+
+            #[inline]
+            #[must_use]
+            pub fn $row_as_flat(&self, i: usize) -> &'a [T::Element] {
+                packed_row_as_flat(self.data, self.vectors_per_row, self.row_length, i)
+            }
+
+            #[inline]
+            #[must_use]
+            pub fn $row_iter(&self) -> Matrix2DIter<'a, T, $strategy> {
+                Matrix2DIter::new(self.data, self.rows, self.vectors_per_row, self.row_length)
+            }
+
+            #[inline]
+            #[must_use]
+            pub fn $row_iter_as_flat(&self) -> Matrix2DFlatIter<'a, T, $strategy> {
+                Matrix2DFlatIter::new(self.data, self.rows, self.vectors_per_row, self.row_length)
+            }
+        }
+
+        impl<T> MatSimdMut<'_, T, $strategy>
+        where
+            T: Simd,
+        {
+            #[inline]
+            #[must_use]
+            pub fn $row(&self, i: usize) -> &[T] {
+                packed_row(self.data, self.vectors_per_row, i)
+            }
+
+            #[inline]
+            pub fn $row_mut(&mut self, i: usize) -> &mut [T] {
+                packed_row_mut(self.data, self.vectors_per_row, i)
+            }
+
+            #[inline]
+            #[must_use]
+            pub fn $row_as_flat(&self, i: usize) -> &[T::Element] {
+                packed_row_as_flat(self.data, self.vectors_per_row, self.row_length, i)
+            }
+
+            #[inline]
+            pub fn $row_as_flat_mut(&mut self, i: usize) -> &mut [T::Element] {
+                packed_row_as_flat_mut(self.data, self.vectors_per_row, self.row_length, i)
+            }
+
+            #[inline]
+            #[must_use]
+            pub fn $row_iter(&self) -> Matrix2DIter<'_, T, $strategy> {
+                Matrix2DIter::new(self.data, self.rows, self.vectors_per_row, self.row_length)
+            }
+
+            #[inline]
+            pub fn $row_iter_mut(&mut self) -> Matrix2DIterMut<'_, T, $strategy> {
+                Matrix2DIterMut::new(self.data, self.rows, self.vectors_per_row, self.row_length)
+            }
+
+            #[inline]
+            #[must_use]
+            pub fn $row_iter_as_flat(&self) -> Matrix2DFlatIter<'_, T, $strategy> {
+                Matrix2DFlatIter::new(self.data, self.rows, self.vectors_per_row, self.row_length)
+            }
+
+            #[inline]
+            pub fn $row_iter_as_flat_mut(&mut self) -> Matrix2DFlatIterMut<'_, T, $strategy> {
+                Matrix2DFlatIterMut::new(self.data, self.rows, self.vectors_per_row, self.row_length)
+            }
+        }
+    };
+}
+
+#[rustfmt::skip]
+impl_borrowed_mat!(Rows, row, row_mut, row_as_flat, row_as_flat_mut, row_iter, row_iter_mut, row_iter_as_flat, row_iter_as_flat_mut);
+#[rustfmt::skip]
+impl_borrowed_mat!(Columns, column, column_mut, column_as_flat, column_as_flat_mut, column_iter, column_iter_mut, column_iter_as_flat, column_iter_as_flat_mut);
+
+impl<T> VecSimd<T>
+where
+    T: Simd,
+{
+    /// Returns a borrowed view of this vector.
+    #[inline]
+    #[must_use]
+    pub fn as_simd_ref(&self) -> VecSimdRef<'_, T> {
+        VecSimdRef {
+            data: &self.simd_rows.data,
+            length: self.simd_rows.row_length,
+        }
+    }
+
+    /// Returns a borrowed, mutable view of this vector.
+    ///
+    /// Writes through SIMD vectors can change padding lanes, use [`reset_padding`](Self::reset_padding) afterwards if needed.
+    #[inline]
+    pub fn as_simd_mut(&mut self) -> VecSimdMut<'_, T> {
+        VecSimdMut {
+            data: &mut self.simd_rows.data,
+            length: self.simd_rows.row_length,
+        }
+    }
+}
+
+impl<T, A> MatSimd<T, A>
+where
+    T: Simd,
+    A: AccessStrategy,
+{
+    /// Returns a borrowed view of this matrix.
+    #[inline]
+    #[must_use]
+    pub fn as_simd_ref(&self) -> MatSimdRef<'_, T, A> {
+        MatSimdRef::from_parts(&self.simd_rows.data, self.simd_rows.rows, self.simd_rows.row_length, self.simd_rows.vectors_per_row)
+    }
+
+    /// Returns a borrowed, mutable view of this matrix.
+    ///
+    /// Writes through SIMD vectors can change padding lanes, use [`reset_padding`](Self::reset_padding) afterwards if needed.
+    #[inline]
+    pub fn as_simd_mut(&mut self) -> MatSimdMut<'_, T, A> {
+        MatSimdMut {
+            data: &mut self.simd_rows.data,
+            rows: self.simd_rows.rows,
+            row_length: self.simd_rows.row_length,
+            vectors_per_row: self.simd_rows.vectors_per_row,
+            phantom: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{MatSimdMut, MatSimdRef, VecSimdMut, VecSimdRef};
+    use crate::{arch::i32x4, Columns, Error, MatSimd, Rows, VecSimd};
+
+    #[test]
+    fn vec_views() {
+        let mut storage = [i32x4::splat(0); 2];
+
+        assert_eq!(VecSimdRef::new(&storage, 9).unwrap_err(), Error::DimensionMismatch { expected: 3, actual: 2 });
+        assert!(VecSimdRef::new(&storage, 4).is_err());
+
+        let mut v = VecSimdMut::new(&mut storage, 6).unwrap();
+
+        v.flat_mut().copy_from_slice(&[1, 2, 3, 4, 5, 6]);
+        v[1] += i32x4::splat(10);
+
+        assert_eq!(v.as_simd_ref().flat(), &[1, 2, 3, 4, 15, 16]);
+        assert_eq!(v.len(), 6);
+        assert_eq!(storage[1].to_array(), [15, 16, 10, 10]);
+
+        let mut owned = VecSimd::<i32x4>::from_slice(&[1, 2, 3]);
+
+        owned.as_simd_mut().flat_mut()[2] = 7;
+
+        assert_eq!(owned.as_simd_ref().flat(), &[1, 2, 7]);
+        assert_eq!(owned.as_simd_ref().simd_chunks().len(), 1);
+    }
+
+    #[test]
+    fn mat_views() {
+        let mut storage = vec![i32x4::splat(0); 6];
+
+        assert!(MatSimdRef::<i32x4, Rows>::new(&storage, 2, 5).is_err());
+        assert!(MatSimdRef::<i32x4, Columns>::new(&storage, usize::MAX, 3).is_err());
+
+        let mut m = MatSimdMut::<i32x4, Columns>::new(&mut storage, 7, 3).unwrap();
+
+        m.column_as_flat_mut(2)[6] = 5;
+        m.flat_mut()[(0, 1)] = 3;
+
+        for column in m.column_iter_as_flat_mut() {
+            column[1] += 1;
+        }
+
+        assert_eq!(m.dimension(), (7, 3));
+        assert_eq!(m.column_as_flat(1), &[3, 1, 0, 0, 0, 0, 0]);
+        assert_eq!(m.as_simd_ref().flat()[(6, 2)], 5);
+
+        let r = MatSimdRef::<i32x4, Rows>::new(&storage, 3, 8).unwrap();
+
+        assert_eq!(r.row(2)[1].to_array(), [0, 0, 5, 0]);
+        assert_eq!(r.row_iter().len(), 3);
+        assert_eq!(r.row_iter_as_flat().nth(1).unwrap(), &[3, 1, 0, 0, 0, 0, 0, 0]);
+
+        let mut owned = MatSimd::<i32x4, Rows>::with_dimension(2, 3);
+
+        owned.as_simd_mut().row_as_flat_mut(1)[2] = 9;
+
+        assert_eq!(owned.as_simd_ref().row_as_flat(1), &[0, 0, 9]);
+        assert_eq!(owned.flat()[(1, 2)], 9);
+    }
+}
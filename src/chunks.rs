@@ -0,0 +1,239 @@
+use std::iter::FusedIterator;
+
+use crate::{packed::PackedMxN, traits::Simd, Columns, MatSimd, Rows, VecSimd};
+
+/// A SIMD vector of a row, along with how many of its lanes hold elements.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SimdChunk<T> {
+    /// A vector where all lanes hold elements.
+    Full(T),
+    /// The partial last vector of a row, where only the given number of lanes hold elements.
+    /// The remaining lanes are padding.
+    Tail(T, usize),
+}
+
+impl<T> SimdChunk<T>
+where
+    T: Simd,
+{
+    /// Returns the vector, including padding lanes.
+    #[inline]
+    pub const fn vector(self) -> T {
+        match self {
+            Self::Full(x) | Self::Tail(x, _) => x,
+        }
+    }
+
+    /// Returns the number of lanes holding elements.
+    #[inline]
+    pub const fn lanes(&self) -> usize {
+        match self {
+            Self::Full(_) => T::LANES,
+            Self::Tail(_, lanes) => *lanes,
+        }
+    }
+
+    /// Returns the vector with all padding lanes set to `value`, e.g., the identity of a reduction.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use simd_aligned::{VecSimd, arch::i32x4, traits::Simd};
+    ///
+    /// let v = VecSimd::<i32x4>::from_slice(&[2, 3, 4, 5, 6]);
+    /// let product = v.simd_chunks().fold(i32x4::splat(1), |acc, x| acc * x.masked(1));
+    ///
+    /// assert_eq!(product.reduce_product(), 720);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn masked(self, value: T::Element) -> T {
+        match self {
+            Self::Full(x) => x,
+            Self::Tail(mut x, lanes) => {
+                x.as_array_mut()[lanes..].fill(value);
+                x
+            }
+        }
+    }
+}
+
+/// Iterator over the SIMD vectors of a row, telling full vectors and the partial last vector apart.
+///
+/// Produced by [`VecSimd::simd_chunks`], [`MatSimd::row_simd_chunks`] and [`MatSimd::column_simd_chunks`].
+#[derive(Clone, Debug)]
+pub struct SimdChunks<'a, T> {
+    vectors: &'a [T],
+    tail: usize,
+}
+
+impl<'a, T> SimdChunks<'a, T>
+where
+    T: Simd,
+{
+    /// Creates an iterator over the vectors of a row holding `row_length` elements.
+    #[inline]
+    pub(crate) const fn new(vectors: &'a [T], row_length: usize) -> Self {
+        let tail = match row_length % T::LANES {
+            0 => T::LANES,
+            x => x,
+        };
+
+        Self { vectors, tail }
+    }
+
+    /// Wraps the last vector of the row.
+    #[inline]
+    const fn chunk(&self, x: T, last: bool) -> SimdChunk<T> {
+        if last && self.tail < T::LANES {
+            SimdChunk::Tail(x, self.tail)
+        } else {
+            SimdChunk::Full(x)
+        }
+    }
+}
+
+impl<T> Iterator for SimdChunks<'_, T>
+where
+    T: Simd,
+{
+    type Item = SimdChunk<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (first, rest) = self.vectors.split_first()?;
+        self.vectors = rest;
+        Some(self.chunk(*first, rest.is_empty()))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.vectors.len(), Some(self.vectors.len()))
+    }
+}
+
+impl<T> DoubleEndedIterator for SimdChunks<'_, T>
+where
+    T: Simd,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (last, rest) = self.vectors.split_last()?;
+        let rval = self.chunk(*last, true);
+
+        self.vectors = rest;
+        self.tail = T::LANES;
+        Some(rval)
+    }
+}
+
+impl<T> ExactSizeIterator for SimdChunks<'_, T> where T: Simd {}
+
+impl<T> FusedIterator for SimdChunks<'_, T> where T: Simd {}
+
+impl<T> PackedMxN<T>
+where
+    T: Simd,
+{
+    /// Returns an iterator over the vectors of the given row.
+    #[inline]
+    pub(crate) fn row_simd_chunks(&self, row: usize) -> SimdChunks<'_, T> {
+        SimdChunks::new(&self.data[self.range_for_row(row)], self.row_length)
+    }
+}
+
+impl<T> VecSimd<T>
+where
+    T: Simd,
+{
+    /// Returns an iterator over all SIMD vectors, yielding the partial last vector as [`SimdChunk::Tail`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use simd_aligned::{SimdChunk, VecSimd, arch::f32x4};
+    ///
+    /// let v = VecSimd::<f32x4>::from_slice(&[1.0, 2.0, 3.0, 4.0, 5.0]);
+    /// let lanes = v.simd_chunks().map(|x| x.lanes()).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(lanes, [4, 1]);
+    /// assert!(matches!(v.simd_chunks().next_back(), Some(SimdChunk::Tail(_, 1))));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn simd_chunks(&self) -> SimdChunks<'_, T> {
+        self.simd_rows.row_simd_chunks(0)
+    }
+}
+
+impl<T> MatSimd<T, Rows>
+where
+    T: Simd,
+{
+    /// Returns an iterator over the SIMD vectors of row `i`, yielding the partial last vector as [`SimdChunk::Tail`].
+    #[inline]
+    #[must_use]
+    pub fn row_simd_chunks(&self, i: usize) -> SimdChunks<'_, T> {
+        self.simd_rows.row_simd_chunks(i)
+    }
+}
+
+impl<T> MatSimd<T, Columns>
+where
+    T: Simd,
+{
+    /// Returns an iterator over the SIMD vectors of column `i`, yielding the partial last vector as [`SimdChunk::Tail`].
+    #[inline]
+    #[must_use]
+    pub fn column_simd_chunks(&self, i: usize) -> SimdChunks<'_, T> {
+        self.simd_rows.row_simd_chunks(i)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SimdChunk;
+    use crate::{arch::i32x4, traits::Simd, Columns, MatSimd, Padding, Rows, VecSimd};
+
+    #[test]
+    fn vec_chunks() {
+        let v = VecSimd::<i32x4>::from_slice(&[1, 2, 3, 4, 5, 6]);
+        let chunks = v.simd_chunks().collect::<Vec<_>>();
+
+        assert_eq!(chunks, [SimdChunk::Full(i32x4::from([1, 2, 3, 4])), SimdChunk::Tail(i32x4::from([5, 6, 0, 0]), 2)]);
+        assert_eq!(v.simd_chunks().rev().map(|x| x.lanes()).collect::<Vec<_>>(), [2, 4]);
+        assert_eq!(v.simd_chunks().len(), 2);
+
+        let mut iter = v.simd_chunks();
+
+        assert_eq!(iter.next_back().map(|x| x.lanes()), Some(2));
+        assert_eq!(iter.next().map(|x| x.lanes()), Some(4));
+        assert_eq!(iter.next(), None);
+
+        let full = VecSimd::<i32x4>::with(1, 8);
+
+        assert!(full.simd_chunks().all(|x| matches!(x, SimdChunk::Full(_))));
+        assert_eq!(VecSimd::<i32x4>::with(1, 0).simd_chunks().next(), None);
+    }
+
+    #[test]
+    fn masked() {
+        let mut v = VecSimd::<i32x4>::from_slice(&[1, 2, 3, 4, 5]);
+
+        v.set_padding(Padding::Value(9));
+
+        let sum = v.simd_chunks().fold(i32x4::splat(0), |acc, x| acc + x.masked(0));
+
+        assert_eq!(sum.sum(), 15);
+        assert_eq!(v.simd_chunks().next_back().map(SimdChunk::vector), Some(i32x4::from([5, 9, 9, 9])));
+    }
+
+    #[test]
+    fn mat_chunks() {
+        let rows = MatSimd::<i32x4, Rows>::with_dimension(3, 5);
+        let columns = MatSimd::<i32x4, Columns>::with_dimension(8, 2);
+
+        assert_eq!(rows.row_simd_chunks(2).map(|x| x.lanes()).collect::<Vec<_>>(), [4, 1]);
+        assert_eq!(columns.column_simd_chunks(1).map(|x| x.lanes()).collect::<Vec<_>>(), [4, 4]);
+    }
+}
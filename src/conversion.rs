@@ -1,4 +1,4 @@
-use crate::traits::Simd;
+use crate::{packed::PackedMxN, traits::Simd, AccessStrategy, Error};
 
 #[inline]
 pub const fn simd_container_flat_slice<T>(data: &[T], length: usize) -> &[T::Element]
@@ -18,7 +18,7 @@ where
 }
 
 #[inline]
-pub fn simd_container_flat_slice_mut<T>(data: &mut [T], length: usize) -> &mut [T::Element]
+pub const fn simd_container_flat_slice_mut<T>(data: &mut [T], length: usize) -> &mut [T::Element]
 where
     T: Simd + Default + Clone,
 {
@@ -28,6 +28,83 @@ where
     unsafe { std::slice::from_raw_parts_mut(mut_ptr, length) }
 }
 
+/// Checks that `length` vectors hold `rows` packed rows of `row_length` elements, returning the vectors per row.
+#[inline]
+pub const fn packed_vectors_per_row<T>(length: usize, rows: usize, row_length: usize) -> Result<usize, Error>
+where
+    T: Simd + Default + Clone,
+{
+    let vectors_per_row = PackedMxN::<T>::vectors_for_length(row_length);
+    let expected = rows.saturating_mul(vectors_per_row);
+
+    if length == expected {
+        Ok(vectors_per_row)
+    } else {
+        Err(Error::DimensionMismatch { expected, actual: length })
+    }
+}
+
+/// Checks that `length` vectors hold `rows` packed rows of `row_length` elements laid out by `A`,
+/// returning the vectors per row.
+#[inline]
+pub fn strategy_vectors_per_row<T, A>(length: usize, rows: usize, row_length: usize) -> Result<usize, Error>
+where
+    T: Simd + Default + Clone,
+    A: AccessStrategy,
+{
+    let vectors_per_row = A::vectors_per_row(row_length, T::LANES);
+    let storage_rows = A::storage_rows(rows);
+    let expected = vectors_per_row.zip(storage_rows).map_or(usize::MAX, |(x, y)| x.saturating_mul(y));
+
+    match vectors_per_row {
+        Some(x) if length == expected => Ok(x),
+        _ => Err(Error::DimensionMismatch { expected, actual: length }),
+    }
+}
+
+/// Returns the offset of element `index` of packed row `row` in the flat storage laid out by `A`,
+/// or `None` if it's outside `rows` rows of `row_length` elements.
+#[inline]
+pub fn strategy_flat_offset<T, A>(rows: usize, row_length: usize, vectors_per_row: usize, row: usize, index: usize) -> Option<usize>
+where
+    T: Simd + Default + Clone,
+    A: AccessStrategy,
+{
+    (row < rows && index < row_length).then(|| A::vector_index(row, index / T::LANES, vectors_per_row) * T::LANES + index % T::LANES)
+}
+
+/// Returns the SIMD vectors of packed row `row`.
+#[inline]
+pub fn packed_row<T>(data: &[T], vectors_per_row: usize, row: usize) -> &[T] {
+    let start = row * vectors_per_row;
+    &data[start..start + vectors_per_row]
+}
+
+/// Returns the SIMD vectors of packed row `row`, allowing modification.
+#[inline]
+pub fn packed_row_mut<T>(data: &mut [T], vectors_per_row: usize, row: usize) -> &mut [T] {
+    let start = row * vectors_per_row;
+    &mut data[start..start + vectors_per_row]
+}
+
+/// Returns the elements of packed row `row`.
+#[inline]
+pub fn packed_row_as_flat<T>(data: &[T], vectors_per_row: usize, row_length: usize, row: usize) -> &[T::Element]
+where
+    T: Simd + Default + Clone,
+{
+    simd_container_flat_slice(packed_row(data, vectors_per_row, row), row_length)
+}
+
+/// Returns the elements of packed row `row`, allowing modification.
+#[inline]
+pub fn packed_row_as_flat_mut<T>(data: &mut [T], vectors_per_row: usize, row_length: usize, row: usize) -> &mut [T::Element]
+where
+    T: Simd + Default + Clone,
+{
+    simd_container_flat_slice_mut(packed_row_mut(data, vectors_per_row, row), row_length)
+}
+
 /// Converts an slice of SIMD vectors into a flat slice of elements.
 ///
 /// # Example
@@ -60,7 +137,7 @@ where
 /// assert_eq!(flat.len(), 16);
 /// ```
 #[inline]
-pub fn packed_as_flat_mut<T>(data: &mut [T]) -> &mut [T::Element]
+pub const fn packed_as_flat_mut<T>(data: &mut [T]) -> &mut [T::Element]
 where
     T: Simd + Default + Clone,
 {
@@ -68,6 +145,7 @@ where
 }
 
 #[cfg(test)]
+#[allow(clippy::zero_repeat_side_effects)] // Keeps the original tests as written.
 mod test {
     use super::{packed_as_flat, packed_as_flat_mut};
     use crate::arch::f32x4;
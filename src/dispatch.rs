@@ -0,0 +1,263 @@
+//! Runtime selection of instruction sets for the kernels of this crate.
+//!
+//! This is not a replacement for compile-time `target_feature`: it doesn't make vectors wider at
+//! runtime. Vectors such as [`f32x8`](crate::arch::f32x8) come from `wide`, which picks their
+//! implementation from the target features enabled at build time. Without `-C target-cpu` or
+//! `-C target-feature`, an `f32x8` on `x86_64` is two SSE2 vectors, whatever level is detected.
+//! To get 256 bit arithmetic for the `wide` types, enable AVX2 at build time.
+//!
+//! What the [`Level`] detected at runtime does select:
+//!
+//! - Kernels written with `std::arch` intrinsics, currently the tile transpose behind
+//!   [`MatSimd::transpose`](crate::MatSimd::transpose), [`to_rows`](crate::MatSimd::to_rows) and
+//!   [`to_columns`](crate::MatSimd::to_columns) and the strided gather behind
+//!   [`MatSimd::gather_column`](crate::MatSimd::gather_column), use wider or dedicated
+//!   instructions from `Sse41` and `Avx2`.
+//! - Reductions, dot products and matrix kernels on [`VecSimd`](crate::VecSimd) and
+//!   [`MatSimd`](crate::MatSimd) are compiled once per level, each copy in its own function with
+//!   `#[target_feature]` for the level, and the copy for the current level is called. This only
+//!   changes the instruction encoding, e.g., to VEX encoded instructions, as the vector width
+//!   stays that of the build target.
+//!
+//! AVX-512 is not detected and has no level. Its `target_feature` needs a newer Rust than this
+//! crate supports, and without 512 bit vectors in `wide` it would only change the encoding again.
+//!
+//! Dispatch only changes how kernels are compiled, never the storage. Data is always processed
+//! in the vector type it was allocated with, and since every level performs the same operations
+//! in the same order, results are bit-identical on all levels.
+//!
+//! The level is detected once. It can be lowered with [`set_level`], or by setting the
+//! environment variable `SIMD_ALIGNED_LEVEL` to `baseline`, `sse4.1` or `avx2` before the first
+//! kernel runs, e.g., to test the fallback path.
+//!
+//! # Example
+//!
+//! ```rust
+//! use simd_aligned::dispatch::{self, Level};
+//!
+//! let previous = dispatch::level();
+//!
+//! assert_eq!(dispatch::set_level(Level::Baseline), Level::Baseline);
+//! assert!(dispatch::set_level(Level::Avx2) <= dispatch::detected());
+//!
+//! dispatch::set_level(previous);
+//! ```
+use std::sync::{
+    atomic::{AtomicU8, Ordering},
+    OnceLock,
+};
+
+/// Instruction set levels kernels can be compiled for, from lowest to highest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Level {
+    /// The fallback path. Kernels run as compiled for the build target, which is scalar code on
+    /// targets without SIMD support.
+    Baseline,
+    /// SSE4.1 on `x86` and `x86_64`.
+    Sse41,
+    /// AVX2 on `x86` and `x86_64`.
+    Avx2,
+}
+
+impl Level {
+    const ALL: [Self; 3] = [Self::Baseline, Self::Sse41, Self::Avx2];
+
+    /// Parses the names accepted by `SIMD_ALIGNED_LEVEL`.
+    fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "baseline" | "scalar" => Some(Self::Baseline),
+            "sse4.1" | "sse41" => Some(Self::Sse41),
+            "avx2" => Some(Self::Avx2),
+            _ => None,
+        }
+    }
+}
+
+/// Marks the current level as not yet initialized.
+const UNINITIALIZED: u8 = u8::MAX;
+
+static LEVEL: AtomicU8 = AtomicU8::new(UNINITIALIZED);
+
+/// Returns the highest level supported by this machine.
+#[must_use]
+pub fn detected() -> Level {
+    static DETECTED: OnceLock<Level> = OnceLock::new();
+
+    *DETECTED.get_or_init(|| {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            if is_x86_feature_detected!("avx2") {
+                return Level::Avx2;
+            }
+
+            if is_x86_feature_detected!("sse4.1") {
+                return Level::Sse41;
+            }
+        }
+
+        Level::Baseline
+    })
+}
+
+/// Returns the level kernels currently run with.
+///
+/// Unless changed with [`set_level`], this is the [`detected`] level, or the level named by
+/// `SIMD_ALIGNED_LEVEL` if that is lower.
+#[must_use]
+pub fn level() -> Level {
+    match LEVEL.load(Ordering::Relaxed) {
+        UNINITIALIZED => set_level(requested(std::env::var("SIMD_ALIGNED_LEVEL").ok().as_deref())),
+        x => Level::ALL[usize::from(x)],
+    }
+}
+
+/// Returns the level requested by the value of `SIMD_ALIGNED_LEVEL`, unknown names and a missing
+/// variable request the highest level.
+fn requested(variable: Option<&str>) -> Level {
+    variable.and_then(Level::from_name).unwrap_or(Level::Avx2)
+}
+
+/// Sets the level kernels run with and returns the level actually used.
+///
+/// Levels above the [`detected`] one can't be used safely, so they are lowered to it.
+pub fn set_level(level: Level) -> Level {
+    let level = level.min(detected());
+
+    LEVEL.store(level as u8, Ordering::Relaxed);
+    level
+}
+
+/// Defines a kernel as a module `$name` with one variant per level.
+///
+/// Each variant calls the `#[inline(always)]` body, so every variant gets its own copy compiled
+/// with the `#[target_feature]` of its level. Closures passed to a kernel are inlined into the
+/// variant where the compiler sees fit. Callers read [`level`] once per operation and pass it to
+/// `$name::at` for each block.
+macro_rules! kernel {
+    (
+        $(#[$attr:meta])*
+        pub fn $name:ident<$($generic:ident),*>($($arg:ident: $ty:ty),* $(,)?) $(-> $ret:ty)?
+        where [$($bounds:tt)*]
+        $body:block
+    ) => {
+        $(#[$attr])*
+        ///
+        /// Compiled once per level, see [`dispatch`](crate::dispatch).
+        pub mod $name {
+            #[allow(clippy::wildcard_imports)]
+            use super::*;
+
+            /// The kernel body, for use in other kernels.
+            #[inline(always)]
+            pub fn body<$($generic),*>($($arg: $ty),*) $(-> $ret)?
+            where
+                $($bounds)*
+            $body
+
+            /// Runs the variant for `level`, which must not exceed
+            /// [`detected`](crate::dispatch::detected), as guaranteed for
+            /// [`level`](crate::dispatch::level).
+            #[inline]
+            pub fn at<$($generic),*>(level: $crate::dispatch::Level, $($arg: $ty),*) $(-> $ret)?
+            where
+                $($bounds)*
+            {
+                debug_assert!(level <= $crate::dispatch::detected());
+
+                match level {
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    // SAFETY: Levels are never above the detected one, see above.
+                    $crate::dispatch::Level::Avx2 => unsafe { avx2($($arg),*) },
+                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                    // SAFETY: Levels are never above the detected one, see above.
+                    $crate::dispatch::Level::Sse41 => unsafe { sse41($($arg),*) },
+                    _ => baseline($($arg),*),
+                }
+            }
+
+            /// The variant for [`Level::Baseline`](crate::dispatch::Level::Baseline).
+            pub fn baseline<$($generic),*>($($arg: $ty),*) $(-> $ret)?
+            where
+                $($bounds)*
+            {
+                body($($arg),*)
+            }
+
+            /// The variant for [`Level::Sse41`](crate::dispatch::Level::Sse41).
+            ///
+            /// # Safety
+            ///
+            /// The CPU must support SSE4.1.
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            #[target_feature(enable = "sse4.1")]
+            pub unsafe fn sse41<$($generic),*>($($arg: $ty),*) $(-> $ret)?
+            where
+                $($bounds)*
+            {
+                body($($arg),*)
+            }
+
+            /// The variant for [`Level::Avx2`](crate::dispatch::Level::Avx2).
+            ///
+            /// # Safety
+            ///
+            /// The CPU must support AVX2.
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            #[target_feature(enable = "avx2")]
+            pub unsafe fn avx2<$($generic),*>($($arg: $ty),*) $(-> $ret)?
+            where
+                $($bounds)*
+            {
+                body($($arg),*)
+            }
+        }
+    };
+}
+
+pub(crate) use kernel;
+
+#[cfg(test)]
+mod test {
+    use super::{detected, requested, Level};
+    use crate::{arch::f32x8, kernels, traits::Simd};
+
+    #[test]
+    fn names() {
+        assert_eq!(Level::from_name("SSE4.1"), Some(Level::Sse41));
+        assert_eq!(Level::from_name(" avx2 "), Some(Level::Avx2));
+        assert_eq!(Level::from_name("baseline"), Some(Level::Baseline));
+        assert_eq!(Level::from_name("avx1024"), None);
+    }
+
+    #[test]
+    fn environment() {
+        assert_eq!(requested(Some("baseline")), Level::Baseline);
+        assert_eq!(requested(Some("Sse4.1")), Level::Sse41);
+        assert_eq!(requested(Some("avx1024")), Level::Avx2);
+        assert_eq!(requested(None), Level::Avx2);
+    }
+
+    #[test]
+    fn levels_agree() {
+        let flat = (0..3001_u16).map(|i| f32::from(i % 89) / 9.0 - 4.0).collect::<Vec<_>>();
+        let x = flat
+            .chunks(8)
+            .map(|c| f32x8::from_array(std::array::from_fn(|i| c.get(i).copied().unwrap_or(9.0))))
+            .collect::<Vec<_>>();
+        let y = &x[1..];
+
+        let compute = |level| {
+            let dot = kernels::dot_accumulate::at(level, f32x8::default(), &x[..y.len()], y);
+            let asum = kernels::abs_accumulate::at(level, &x);
+
+            (kernels::dot_finish(&dot, &x[..y.len()], y, 2990).to_bits(), asum.to_array().map(f32::to_bits))
+        };
+
+        let baseline = compute(Level::Baseline);
+
+        for level in Level::ALL.into_iter().filter(|level| *level <= detected()) {
+            assert_eq!(compute(level), baseline);
+        }
+    }
+}
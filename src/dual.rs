@@ -0,0 +1,405 @@
+use std::ops::{Index, IndexMut};
+
+use crate::{mat::out_of_bounds, traits::Simd, Columns, Error, MatFlat, MatSimd, Padding, Rows};
+
+/// A matrix stored twice, packed by rows and by columns, so both are available as SIMD vectors.
+///
+/// Algorithms like coordinate descent need fast access to rows and columns of the same matrix.
+/// This type keeps a [`MatSimd<T, Rows>`] and a [`MatSimd<T, Columns>`] with the same content,
+/// at twice the memory.
+///
+/// Writes through [`flat_mut`](Self::flat_mut) update both copies. Writes through
+/// [`row_mut`](Self::row_mut) and [`row_as_flat_mut`](Self::row_as_flat_mut) only change the
+/// row copy, call [`sync`](Self::sync) after a batch of them.
+///
+/// # Example
+///
+/// ```rust
+/// use simd_aligned::{MatSimdDual, arch::f32x4};
+///
+/// let mut m = MatSimdDual::<f32x4>::with_dimension(3, 5);
+///
+/// m.flat_mut()[(2, 4)] = 1.0;
+/// assert_eq!(m.column_as_flat(4), &[0.0, 0.0, 1.0]);
+///
+/// m.row_as_flat_mut(0).fill(2.0);
+/// m.sync();
+/// assert_eq!(m.column_as_flat(4), &[2.0, 0.0, 1.0]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct MatSimdDual<T>
+where
+    T: Simd + Default + Clone,
+{
+    rows: MatSimd<T, Rows>,
+    columns: MatSimd<T, Columns>,
+}
+
+impl<T> MatSimdDual<T>
+where
+    T: Simd + Default + Clone,
+{
+    /// Creates a new matrix with the given dimension.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size overflows or the allocation fails, see [`try_with_dimension`](Self::try_with_dimension).
+    #[inline]
+    #[must_use]
+    pub fn with_dimension(rows: usize, columns: usize) -> Self {
+        Self::try_with_dimension(rows, columns).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Creates a new matrix with the given dimension, reporting overflows and allocation failures.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::CapacityOverflow`] or [`Error::AllocationFailed`] if either copy can't be allocated.
+    #[inline]
+    pub fn try_with_dimension(rows: usize, columns: usize) -> Result<Self, Error> {
+        Ok(Self {
+            rows: MatSimd::try_with_dimension(rows, columns)?,
+            columns: MatSimd::try_with_dimension(rows, columns)?,
+        })
+    }
+
+    /// Returns the size as (`rows`, `columns`).
+    #[inline]
+    #[must_use]
+    pub fn dimension(&self) -> (usize, usize) {
+        self.rows.dimension()
+    }
+
+    /// Returns the [`Padding`] policy of both copies.
+    #[inline]
+    #[must_use]
+    pub const fn padding(&self) -> Padding<T::Element> {
+        self.rows.padding()
+    }
+
+    /// Changes the [`Padding`] policy of both copies and applies it.
+    #[inline]
+    pub fn set_padding(&mut self, padding: Padding<T::Element>) {
+        self.rows.set_padding(padding);
+        self.columns.set_padding(padding);
+    }
+
+    /// Returns the copy packed by rows.
+    #[inline]
+    #[must_use]
+    pub const fn as_rows(&self) -> &MatSimd<T, Rows> {
+        &self.rows
+    }
+
+    /// Returns the copy packed by columns.
+    #[inline]
+    #[must_use]
+    pub const fn as_columns(&self) -> &MatSimd<T, Columns> {
+        &self.columns
+    }
+
+    /// Returns the SIMD vectors of row `i`.
+    #[inline]
+    #[must_use]
+    pub fn row(&self, i: usize) -> &[T] {
+        self.rows.row(i)
+    }
+
+    /// Returns the SIMD vectors of column `i`.
+    #[inline]
+    #[must_use]
+    pub fn column(&self, i: usize) -> &[T] {
+        self.columns.column(i)
+    }
+
+    /// Returns the elements of row `i`.
+    #[inline]
+    #[must_use]
+    pub fn row_as_flat(&self, i: usize) -> &[T::Element] {
+        self.rows.row_as_flat(i)
+    }
+
+    /// Returns the elements of column `i`.
+    #[inline]
+    #[must_use]
+    pub fn column_as_flat(&self, i: usize) -> &[T::Element] {
+        self.columns.column_as_flat(i)
+    }
+
+    /// Returns the SIMD vectors of row `i` for modification, changing the row copy only.
+    ///
+    /// Call [`sync`](Self::sync) before reading columns again.
+    #[inline]
+    pub fn row_mut(&mut self, i: usize) -> &mut [T] {
+        self.rows.row_mut(i)
+    }
+
+    /// Returns the elements of row `i` for modification, changing the row copy only.
+    ///
+    /// Call [`sync`](Self::sync) before reading columns again.
+    #[inline]
+    pub fn row_as_flat_mut(&mut self, i: usize) -> &mut [T::Element] {
+        self.rows.row_as_flat_mut(i)
+    }
+
+    /// Provides a flat, immutable view of the contained data.
+    #[inline]
+    #[must_use]
+    pub fn flat(&self) -> MatFlat<'_, T, Rows> {
+        self.rows.flat()
+    }
+
+    /// Provides a flat, mutable view that updates both copies.
+    ///
+    /// The view writes to the row copy and updates the columns of all modified rows when dropped.
+    #[inline]
+    pub const fn flat_mut(&mut self) -> MatDualFlatMut<'_, T> {
+        MatDualFlatMut {
+            matrix: self,
+            dirty: Vec::new(),
+            dirty_rows: 0,
+        }
+    }
+
+    /// Copies the row copy into the column copy, after writes through [`row_mut`](Self::row_mut)
+    /// or [`row_as_flat_mut`](Self::row_as_flat_mut).
+    ///
+    /// Also restores the [`Padding`] of the row copy.
+    pub fn sync(&mut self) {
+        self.rows.reset_padding();
+        self.rows.simd_rows.transpose_into::<Rows>(&mut self.columns.simd_rows);
+    }
+
+    /// Copies row `i` of the row copy into the column copy.
+    fn sync_row(&mut self, i: usize) {
+        self.columns.simd_rows.scatter(i, self.rows.row_as_flat(i));
+    }
+}
+
+impl<T> From<MatSimd<T, Rows>> for MatSimdDual<T>
+where
+    T: Simd + Default + Clone,
+{
+    fn from(rows: MatSimd<T, Rows>) -> Self {
+        Self { columns: rows.to_columns(), rows }
+    }
+}
+
+impl<T> From<MatSimd<T, Columns>> for MatSimdDual<T>
+where
+    T: Simd + Default + Clone,
+{
+    fn from(columns: MatSimd<T, Columns>) -> Self {
+        Self { rows: columns.to_rows(), columns }
+    }
+}
+
+/// Copying a single row into the column copy writes one lane per column, while [`MatSimdDual::sync`]
+/// transposes whole tiles. Once at least one in this many rows was modified, a full sync is faster.
+const SYNC_ALL_FRACTION: usize = 8;
+
+/// Provided by [`MatSimdDual::flat_mut`], this allows for flat, mutable access updating both copies.
+///
+/// Writes go to the row copy first, the column copy is updated when the view is dropped. If the
+/// view is leaked instead, [`MatSimdDual::sync`] restores the column copy.
+pub struct MatDualFlatMut<'a, T>
+where
+    T: Simd + Default + Clone,
+{
+    matrix: &'a mut MatSimdDual<T>,
+    /// Bit `i % 64` of word `i / 64` is set if row `i` was modified.
+    dirty: Vec<u64>,
+    dirty_rows: usize,
+}
+
+impl<T> MatDualFlatMut<'_, T>
+where
+    T: Simd + Default + Clone,
+{
+    /// Returns the size as (`rows`, `columns`).
+    #[inline]
+    #[must_use]
+    pub fn dimension(&self) -> (usize, usize) {
+        self.matrix.dimension()
+    }
+
+    /// Returns the element at `(row, column)`, or `None` if it is outside the matrix.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, index: (usize, usize)) -> Option<&T::Element> {
+        self.matrix.rows.get_row(index.0)?;
+        self.matrix.row_as_flat(index.0).get(index.1)
+    }
+
+    /// Returns the element at `(row, column)` for modification, or `None` if it is outside the matrix.
+    #[inline]
+    pub fn get_mut(&mut self, index: (usize, usize)) -> Option<&mut T::Element> {
+        let (rows, columns) = self.dimension();
+
+        if index.0 >= rows || index.1 >= columns {
+            return None;
+        }
+
+        if self.dirty.is_empty() {
+            self.dirty.resize(rows.div_ceil(64), 0);
+        }
+
+        let (word, bit) = (&mut self.dirty[index.0 / 64], 1 << (index.0 % 64));
+
+        if *word & bit == 0 {
+            *word |= bit;
+            self.dirty_rows += 1;
+        }
+
+        Some(&mut self.matrix.row_as_flat_mut(index.0)[index.1])
+    }
+
+    /// Writes `value` to `(row, column)` of both copies right away.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is outside the matrix.
+    #[inline]
+    #[track_caller]
+    pub fn set(&mut self, index: (usize, usize), value: T::Element) {
+        self.matrix.rows.flat_mut()[index] = value;
+        self.matrix.columns.flat_mut()[index] = value;
+    }
+}
+
+impl<T> Index<(usize, usize)> for MatDualFlatMut<'_, T>
+where
+    T: Simd + Default + Clone,
+{
+    type Output = T::Element;
+
+    #[inline]
+    #[track_caller]
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        self.get(index).unwrap_or_else(|| out_of_bounds(index, self.dimension()))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for MatDualFlatMut<'_, T>
+where
+    T: Simd + Default + Clone,
+{
+    #[inline]
+    #[track_caller]
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        let dimension = self.dimension();
+
+        self.get_mut(index).unwrap_or_else(|| out_of_bounds(index, dimension))
+    }
+}
+
+impl<T> Drop for MatDualFlatMut<'_, T>
+where
+    T: Simd + Default + Clone,
+{
+    fn drop(&mut self) {
+        if self.dirty_rows == 0 {
+            return;
+        }
+
+        if self.dirty_rows * SYNC_ALL_FRACTION >= self.matrix.rows.simd_rows.rows {
+            self.matrix.sync();
+            return;
+        }
+
+        for (i, word) in self.dirty.iter().enumerate() {
+            let mut word = *word;
+
+            while word != 0 {
+                self.matrix.sync_row(i * 64 + word.trailing_zeros() as usize);
+                word &= word - 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::MatSimdDual;
+    use crate::{arch::i32x4, MatSimd, Padding, Rows};
+
+    #[test]
+    fn flat_mut_updates_both() {
+        let mut m = MatSimdDual::<i32x4>::with_dimension(6, 5);
+
+        {
+            let mut flat = m.flat_mut();
+
+            flat[(1, 4)] = 14;
+            flat[(3, 0)] = 30;
+            *flat.get_mut((3, 2)).unwrap() = 32;
+            flat.set((5, 1), 51);
+
+            assert_eq!(flat[(1, 4)], 14);
+            assert_eq!(flat.get((6, 0)), None);
+            assert_eq!(flat.get_mut((0, 5)), None);
+        }
+
+        assert_eq!(m.column_as_flat(4), &[0, 14, 0, 0, 0, 0]);
+        assert_eq!(m.column_as_flat(0), &[0, 0, 0, 30, 0, 0]);
+        assert_eq!(m.column_as_flat(2)[3], 32);
+        assert_eq!(m.column_as_flat(1)[5], 51);
+        assert_eq!(m.row_as_flat(3), &[30, 0, 32, 0, 0]);
+        assert_eq!(m.column(1)[1].to_array(), [0, 51, 0, 0]);
+    }
+
+    #[test]
+    fn non_adjacent_rows() {
+        let mut m = MatSimdDual::<i32x4>::with_dimension(32, 5);
+
+        // Only changes the row copy, so it shows which rows the view copies.
+        m.row_as_flat_mut(3).fill(3);
+
+        {
+            let mut flat = m.flat_mut();
+
+            flat[(1, 0)] = 10;
+            flat[(30, 4)] = 304;
+            flat[(1, 2)] = 12;
+        }
+
+        assert_eq!(m.column_as_flat(0)[1], 10);
+        assert_eq!(m.column_as_flat(2)[1], 12);
+        assert_eq!(m.column_as_flat(4)[30], 304);
+        assert_eq!(m.column_as_flat(0)[3], 0);
+
+        // Modifying many rows falls back to a full sync, which includes row 3.
+        {
+            let mut flat = m.flat_mut();
+
+            for row in (0..32).step_by(4) {
+                flat[(row, 1)] = 1;
+            }
+        }
+
+        assert_eq!(m.column_as_flat(0)[3], 3);
+        assert_eq!(m.column_as_flat(1)[28], 1);
+        assert_eq!(m.column_as_flat(4)[30], 304);
+    }
+
+    #[test]
+    fn sync() {
+        let mut r = MatSimd::<i32x4, Rows>::with_dimension(2, 3);
+        r.row_as_flat_mut(1).copy_from_slice(&[4, 5, 6]);
+
+        let mut m = MatSimdDual::from(r);
+        assert_eq!(m.column_as_flat(2), &[0, 6]);
+
+        m.set_padding(Padding::Value(-1));
+        m.row_mut(0)[0] = i32x4::splat(7);
+        assert_eq!(m.column_as_flat(2), &[0, 6]);
+
+        m.sync();
+
+        assert_eq!(m.dimension(), (2, 3));
+        assert_eq!(m.row(0)[0].to_array(), [7, 7, 7, -1]);
+        assert_eq!(m.column_as_flat(2), &[7, 6]);
+        assert_eq!(m.column(0)[0].to_array(), [7, 4, -1, -1]);
+        assert_eq!(m.as_columns().to_rows().row_as_flat(0), m.as_rows().row_as_flat(0));
+    }
+}
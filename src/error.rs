@@ -0,0 +1,74 @@
+use std::fmt::{Display, Formatter};
+
+use crate::format::ElementType;
+
+/// Errors returned by fallible operations of this crate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The shapes of two operands don't fit, e.g., a matrix with `5` columns multiplied by a vector of length `4`.
+    DimensionMismatch {
+        /// The size required by the operation.
+        expected: usize,
+        /// The size that was provided.
+        actual: usize,
+    },
+    /// The requested size doesn't fit the address space, e.g., a matrix of `2^40 x 2^40` elements.
+    CapacityOverflow,
+    /// The memory for a container couldn't be allocated.
+    AllocationFailed {
+        /// The size of the failed allocation in bytes.
+        bytes: usize,
+    },
+    /// The header of a [binary file](crate::format) is malformed, or doesn't fit the type it is read as.
+    InvalidHeader {
+        /// What is wrong with the header.
+        reason: &'static str,
+    },
+    /// A [binary file](crate::format) was written with an unsupported version of the format.
+    UnsupportedVersion {
+        /// The version of the file.
+        version: u16,
+    },
+    /// A [binary file](crate::format) holds a different SIMD type than it is read as.
+    ElementTypeMismatch {
+        /// Element type and lanes of the type read.
+        expected: (ElementType, usize),
+        /// Element type and lanes stored in the file.
+        actual: (ElementType, usize),
+    },
+    /// The data of a [binary file](crate::format) doesn't match its checksum.
+    ChecksumMismatch,
+    /// The bytes of a [binary file](crate::format) are shorter or longer than its header says.
+    SizeMismatch {
+        /// The size in bytes given by the header.
+        expected: usize,
+        /// The number of bytes provided.
+        actual: usize,
+    },
+    /// The data of a [binary file](crate::format) isn't aligned for its SIMD type, so it can't be viewed in place.
+    Misaligned {
+        /// The alignment required by the SIMD type.
+        alignment: usize,
+    },
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DimensionMismatch { expected, actual } => write!(f, "dimension mismatch: expected {expected}, got {actual}"),
+            Self::CapacityOverflow => write!(f, "capacity overflow"),
+            Self::AllocationFailed { bytes } => write!(f, "allocation of {bytes} bytes failed"),
+            Self::InvalidHeader { reason } => write!(f, "invalid header: {reason}"),
+            Self::UnsupportedVersion { version } => write!(f, "unsupported format version {version}"),
+            Self::ElementTypeMismatch { expected, actual } => {
+                write!(f, "element type mismatch: expected {}x{}, got {}x{}", expected.0, expected.1, actual.0, actual.1)
+            }
+            Self::ChecksumMismatch => write!(f, "checksum mismatch"),
+            Self::SizeMismatch { expected, actual } => write!(f, "size mismatch: expected {expected} bytes, got {actual}"),
+            Self::Misaligned { alignment } => write!(f, "data is not aligned to {alignment} bytes"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
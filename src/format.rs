@@ -0,0 +1,708 @@
+//! A compact binary format storing [`VecSimd`] and [`MatSimd`] exactly as they are laid out in memory.
+//!
+//! Unlike serde, which stores the logical elements one by one, this format stores the raw SIMD
+//! vectors, including padding lanes. Loading therefore reads straight into an aligned allocation
+//! without any parsing, but files can only be loaded with the same SIMD type (e.g., `f32x8`),
+//! [`AccessStrategy`](crate::AccessStrategy) and byte order they were written with.
+//!
+//! Files can also be used in place without any copy, e.g., when memory-mapped, through
+//! [`VecSimdView`](crate::VecSimdView) and [`MatSimdView`](crate::MatSimdView).
+//!
+//! # Layout
+//!
+//! A file starts with a header of [`HEADER_SIZE`] bytes. Integers are little endian.
+//!
+//! | Bytes    | Content                                                                          |
+//! |----------|----------------------------------------------------------------------------------|
+//! | `0..8`   | [`MAGIC`]                                                                        |
+//! | `8..10`  | [`VERSION`], `u16`                                                               |
+//! | `10`     | Element type, see [`ElementType`]                                                |
+//! | `11`     | Layout, `0` for [`VecSimd`], `1` for [`Rows`], `2` for [`Columns`]               |
+//! | `12`     | Byte order of the data, `1` for little endian, `2` for big endian                |
+//! | `13`     | [`Padding`] policy, `0` for `Zero`, `1` for `Value`, `2` for `Unspecified`        |
+//! | `14..16` | Lanes per SIMD vector, `u16`                                                     |
+//! | `16..24` | Padding value in data byte order, zero-extended                                  |
+//! | `24..32` | Number of packed rows, `u64`                                                     |
+//! | `32..40` | Elements per packed row, `u64`                                                   |
+//! | `40..48` | Size of the data in bytes, `u64`                                                 |
+//! | `48..56` | Checksum of bytes `0..48` and the data, `u64`                                    |
+//! | `56..64` | Reserved, zero                                                                   |
+//!
+//! The header is followed by the SIMD vectors of all packed rows, i.e., rows for [`Rows`] and
+//! columns for [`Columns`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use simd_aligned::{MatSimd, arch::f32x8, Rows};
+//!
+//! let mut m = MatSimd::<f32x8, Rows>::with_dimension(2, 10);
+//! m.row_as_flat_mut(1)[9] = 42.0;
+//!
+//! let mut bytes = Vec::new();
+//! m.write_to(&mut bytes)?;
+//!
+//! let loaded = MatSimd::<f32x8, Rows>::read_from(bytes.as_slice())?;
+//!
+//! assert_eq!(loaded.row_as_flat(1)[9], 42.0);
+//! # Ok::<(), std::io::Error>(())
+//! ```
+use std::{
+    fmt::{Display, Formatter},
+    io::{self, Read, Seek, SeekFrom, Write},
+    mem::size_of,
+};
+
+use crate::{packed::PackedMxN, traits::Simd, Columns, Error, MatSimd, Padding, Rows, VecSimd};
+
+/// Identifies files of this format.
+pub const MAGIC: [u8; 8] = *b"SIMDALGN";
+
+/// The current version of this format.
+pub const VERSION: u16 = 1;
+
+/// Size of the header in bytes.
+pub const HEADER_SIZE: usize = 64;
+
+/// Bytes of the header covered by the checksum.
+const CHECKED_SIZE: usize = 48;
+
+/// Data up to this many bytes is read with a single allocation and read, even from untrusted
+/// streams of unknown length.
+const TRUSTED_SIZE: usize = 64 << 20;
+
+/// Bytes of data read first when larger data comes from an untrusted stream of unknown length.
+/// Memory then doubles with the data actually read, so a header claiming more data than the
+/// stream holds can't make readers allocate it up front.
+const READ_CHUNK: usize = 1 << 20;
+
+pub(crate) const LAYOUT_VEC: u8 = 0;
+pub(crate) const LAYOUT_ROWS: u8 = 1;
+pub(crate) const LAYOUT_COLUMNS: u8 = 2;
+
+#[cfg(target_endian = "little")]
+const BYTE_ORDER: u8 = 1;
+#[cfg(target_endian = "big")]
+const BYTE_ORDER: u8 = 2;
+
+/// Element types of SIMD vectors, as stored in the header.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ElementType {
+    /// `u8` elements.
+    U8 = 1,
+    /// `i8` elements.
+    I8 = 2,
+    /// `u16` elements.
+    U16 = 3,
+    /// `i16` elements.
+    I16 = 4,
+    /// `u32` elements.
+    U32 = 5,
+    /// `i32` elements.
+    I32 = 6,
+    /// `u64` elements.
+    U64 = 7,
+    /// `i64` elements.
+    I64 = 8,
+    /// `f32` elements.
+    F32 = 9,
+    /// `f64` elements.
+    F64 = 10,
+}
+
+impl ElementType {
+    const ALL: [Self; 10] = [Self::U8, Self::I8, Self::U16, Self::I16, Self::U32, Self::I32, Self::U64, Self::I64, Self::F32, Self::F64];
+
+    fn from_code(code: u8) -> Option<Self> {
+        Self::ALL.into_iter().find(|x| *x as u8 == code)
+    }
+}
+
+impl Display for ElementType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::U8 => "u8",
+            Self::I8 => "i8",
+            Self::U16 => "u16",
+            Self::I16 => "i16",
+            Self::U32 => "u32",
+            Self::I32 => "i32",
+            Self::U64 => "u64",
+            Self::I64 => "i64",
+            Self::F32 => "f32",
+            Self::F64 => "f64",
+        };
+
+        f.write_str(name)
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// SIMD types that can be stored as raw bytes.
+///
+/// This trait is sealed and implemented for all types in [`arch`](crate::arch). They consist of
+/// plain numbers only, so every byte pattern is a valid value.
+pub trait Storable: Simd + private::Sealed {
+    /// The element type stored in the header.
+    const ELEMENT_TYPE: ElementType;
+}
+
+macro_rules! impl_storable {
+    ($($simd:ty => $element_type:ident),*) => {
+        $(
+            impl private::Sealed for $simd {}
+
+            impl Storable for $simd {
+                const ELEMENT_TYPE: ElementType = ElementType::$element_type;
+            }
+        )*
+    };
+}
+
+#[rustfmt::skip]
+impl_storable!(
+    crate::arch::u8x16 => U8,
+    crate::arch::i8x16 => I8, crate::arch::i8x32 => I8,
+    crate::arch::u16x8 => U16, crate::arch::u16x16 => U16,
+    crate::arch::i16x8 => I16, crate::arch::i16x16 => I16,
+    crate::arch::u32x4 => U32, crate::arch::u32x8 => U32,
+    crate::arch::i32x4 => I32, crate::arch::i32x8 => I32,
+    crate::arch::u64x2 => U64, crate::arch::u64x4 => U64,
+    crate::arch::i64x2 => I64, crate::arch::i64x4 => I64,
+    crate::arch::f32x4 => F32, crate::arch::f32x8 => F32,
+    crate::arch::f64x2 => F64, crate::arch::f64x4 => F64
+);
+
+/// Views SIMD vectors as raw bytes.
+#[inline]
+pub(crate) fn packed_as_bytes<T: Storable>(data: &[T]) -> &[u8] {
+    // SAFETY: `Storable` types are plain numbers without padding bytes.
+    unsafe { std::slice::from_raw_parts(data.as_ptr().cast(), std::mem::size_of_val(data)) }
+}
+
+/// Views SIMD vectors as mutable raw bytes.
+#[inline]
+pub(crate) fn packed_as_bytes_mut<T: Storable>(data: &mut [T]) -> &mut [u8] {
+    // SAFETY: `Storable` types are plain numbers without padding bytes, and all byte patterns are valid values.
+    unsafe { std::slice::from_raw_parts_mut(data.as_mut_ptr().cast(), std::mem::size_of_val(data)) }
+}
+
+/// Views raw bytes as SIMD vectors, if they are aligned for `T` and hold whole vectors.
+#[inline]
+pub(crate) fn packed_from_bytes<T: Storable>(bytes: &[u8]) -> Result<&[T], Error> {
+    if bytes.as_ptr().align_offset(std::mem::align_of::<T>()) != 0 {
+        return Err(Error::Misaligned {
+            alignment: std::mem::align_of::<T>(),
+        });
+    }
+
+    if bytes.len() % size_of::<T>() != 0 {
+        return Err(invalid("data size doesn't match dimension"));
+    }
+
+    // SAFETY: The pointer is aligned and the length covers whole vectors. `Storable` types are plain
+    // numbers, so all byte patterns are valid values.
+    Ok(unsafe { std::slice::from_raw_parts(bytes.as_ptr().cast(), bytes.len() / size_of::<T>()) })
+}
+
+/// Hashes `parts` with four interleaved FNV-1a style lanes over 64 bit words.
+fn checksum(parts: &[&[u8]]) -> u64 {
+    const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let mut lanes = [OFFSET, OFFSET ^ 1, OFFSET ^ 2, OFFSET ^ 3];
+
+    for part in parts {
+        let mut chunks = part.chunks_exact(32);
+
+        for chunk in &mut chunks {
+            for (lane, word) in lanes.iter_mut().zip(chunk.chunks_exact(8)) {
+                let word = u64::from_le_bytes(word.try_into().expect("chunks have 8 bytes"));
+                *lane = (*lane ^ word).wrapping_mul(PRIME);
+            }
+        }
+
+        for (i, byte) in chunks.remainder().iter().enumerate() {
+            lanes[i % 4] = (lanes[i % 4] ^ u64::from(*byte)).wrapping_mul(PRIME);
+        }
+    }
+
+    lanes.iter().fold(OFFSET, |hash, lane| (hash ^ lane).wrapping_mul(PRIME))
+}
+
+const fn invalid(reason: &'static str) -> Error {
+    Error::InvalidHeader { reason }
+}
+
+fn invalid_data(error: Error) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
+
+fn read_u64(header: &[u8; HEADER_SIZE], offset: usize) -> u64 {
+    u64::from_le_bytes(header[offset..offset + 8].try_into().expect("8 bytes"))
+}
+
+fn to_usize(x: u64) -> Result<usize, Error> {
+    usize::try_from(x).map_err(|_| invalid("size exceeds the address space"))
+}
+
+/// A validated header, describing the data following it.
+pub(crate) struct Header<T>
+where
+    T: Storable,
+{
+    bytes: [u8; HEADER_SIZE],
+    pub(crate) rows: usize,
+    pub(crate) row_length: usize,
+    pub(crate) vectors_per_row: usize,
+    pub(crate) data_size: usize,
+    pub(crate) padding: Padding<T::Element>,
+}
+
+impl<T> Header<T>
+where
+    T: Storable,
+{
+    /// Parses `bytes` and checks they describe `T` with the given layout code.
+    pub(crate) fn parse(bytes: [u8; HEADER_SIZE], layout: u8) -> Result<Self, Error> {
+        if bytes[0..8] != MAGIC {
+            return Err(invalid("not a simd_aligned file"));
+        }
+
+        let version = u16::from_le_bytes([bytes[8], bytes[9]]);
+
+        if version != VERSION {
+            return Err(Error::UnsupportedVersion { version });
+        }
+
+        let element_type = ElementType::from_code(bytes[10]).ok_or_else(|| invalid("unknown element type"))?;
+        let lanes = usize::from(u16::from_le_bytes([bytes[14], bytes[15]]));
+
+        if element_type != T::ELEMENT_TYPE || lanes != T::LANES {
+            return Err(Error::ElementTypeMismatch {
+                expected: (T::ELEMENT_TYPE, T::LANES),
+                actual: (element_type, lanes),
+            });
+        }
+
+        if bytes[11] != layout {
+            return Err(invalid("layout mismatch"));
+        }
+
+        if bytes[12] != BYTE_ORDER {
+            return Err(invalid("byte order mismatch"));
+        }
+
+        let rows = to_usize(read_u64(&bytes, 24))?;
+        let row_length = to_usize(read_u64(&bytes, 32))?;
+        let data_size = to_usize(read_u64(&bytes, 40))?;
+        let vectors_per_row = PackedMxN::<T>::vectors_for_length(row_length);
+        let vectors = rows.checked_mul(vectors_per_row).ok_or_else(|| invalid("size exceeds the address space"))?;
+
+        if layout == LAYOUT_VEC && rows != 1 {
+            return Err(invalid("vectors must have one row"));
+        }
+
+        if vectors.checked_mul(size_of::<T>()) != Some(data_size) {
+            return Err(invalid("data size doesn't match dimension"));
+        }
+
+        let padding = match bytes[13] {
+            0 => Padding::Zero,
+            1 => {
+                let mut value = [T::default()];
+                let element = size_of::<T::Element>();
+
+                packed_as_bytes_mut(&mut value)[..element].copy_from_slice(&bytes[16..16 + element]);
+                Padding::Value(value[0].as_array()[0])
+            }
+            2 => Padding::Unspecified,
+            _ => return Err(invalid("unknown padding policy")),
+        };
+
+        Ok(Self {
+            bytes,
+            rows,
+            row_length,
+            vectors_per_row,
+            data_size,
+            padding,
+        })
+    }
+
+    /// Checks the checksum of the header and `data`.
+    pub(crate) fn verify(&self, data: &[u8]) -> Result<(), Error> {
+        verify(&self.bytes, data)
+    }
+
+    /// Number of SIMD vectors in the data.
+    pub(crate) const fn vectors(&self) -> usize {
+        self.rows * self.vectors_per_row
+    }
+}
+
+/// Checks the checksum of the `header` bytes and `data`.
+pub(crate) fn verify(header: &[u8; HEADER_SIZE], data: &[u8]) -> Result<(), Error> {
+    if checksum(&[&header[..CHECKED_SIZE], data]) == read_u64(header, 48) {
+        Ok(())
+    } else {
+        Err(Error::ChecksumMismatch)
+    }
+}
+
+/// Writes `packed` with the given layout code.
+fn write_packed<T: Storable>(packed: &PackedMxN<T>, layout: u8, mut writer: impl Write) -> io::Result<()> {
+    let data = packed_as_bytes(&packed.data);
+    let mut header = [0_u8; HEADER_SIZE];
+
+    let (padding, value) = match packed.padding {
+        Padding::Zero => (0, None),
+        Padding::Value(x) => (1, Some(x)),
+        Padding::Unspecified => (2, None),
+    };
+
+    header[0..8].copy_from_slice(&MAGIC);
+    header[8..10].copy_from_slice(&VERSION.to_le_bytes());
+    header[10] = T::ELEMENT_TYPE as u8;
+    header[11] = layout;
+    header[12] = BYTE_ORDER;
+    header[13] = padding;
+    header[14..16].copy_from_slice(&u16::try_from(T::LANES).expect("lanes fit u16").to_le_bytes());
+
+    if let Some(value) = value {
+        let element = size_of::<T::Element>();
+        header[16..16 + element].copy_from_slice(&packed_as_bytes(&[T::splat(value)])[..element]);
+    }
+
+    header[24..32].copy_from_slice(&(packed.rows as u64).to_le_bytes());
+    header[32..40].copy_from_slice(&(packed.row_length as u64).to_le_bytes());
+    header[40..48].copy_from_slice(&(data.len() as u64).to_le_bytes());
+
+    let checksum = checksum(&[&header[..CHECKED_SIZE], data]);
+    header[48..56].copy_from_slice(&checksum.to_le_bytes());
+
+    writer.write_all(&header)?;
+    writer.write_all(data)
+}
+
+/// Returns the number of bytes between the current position of `reader` and its end.
+fn remaining(mut reader: impl Seek) -> io::Result<u64> {
+    let position = reader.stream_position()?;
+    let end = reader.seek(SeekFrom::End(0))?;
+
+    reader.seek(SeekFrom::Start(position))?;
+    Ok(end.saturating_sub(position))
+}
+
+/// Appends `additional` vectors read from `reader` to `data`, reserving exactly that much.
+fn read_vectors<T: Storable>(data: &mut Vec<T>, additional: usize, reader: &mut impl Read) -> io::Result<()> {
+    let start = data.len();
+
+    data.try_reserve_exact(additional).map_err(|_| io::Error::from(io::ErrorKind::OutOfMemory))?;
+    data.resize(start + additional, T::default());
+    reader.read_exact(packed_as_bytes_mut(&mut data[start..]))
+}
+
+/// What is known about the stream when reading data of the size claimed by a header.
+#[derive(Clone, Copy)]
+enum Source {
+    /// The header comes from a trusted source, so its size is allocated and read at once.
+    Trusted,
+    /// The stream holds this many more bytes, so the claimed size can be checked up front.
+    Remaining(u64),
+    /// Nothing is known, so larger data is allocated as it arrives.
+    Untrusted,
+}
+
+/// Reads a `PackedMxN` written with the given layout code from `reader`.
+fn read_packed<T: Storable>(layout: u8, mut reader: impl Read, source: Source) -> io::Result<PackedMxN<T>> {
+    let mut bytes = [0_u8; HEADER_SIZE];
+    reader.read_exact(&mut bytes)?;
+
+    let header = Header::<T>::parse(bytes, layout).map_err(invalid_data)?;
+    let vectors = header.vectors();
+    let mut data = Vec::new();
+
+    match source {
+        Source::Remaining(remaining) if remaining.saturating_sub(HEADER_SIZE as u64) < header.data_size as u64 => {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        Source::Trusted | Source::Remaining(_) => read_vectors(&mut data, vectors, &mut reader)?,
+        Source::Untrusted if header.data_size <= TRUSTED_SIZE => read_vectors(&mut data, vectors, &mut reader)?,
+        Source::Untrusted => {
+            let chunk = READ_CHUNK / size_of::<T>();
+
+            while data.len() < vectors {
+                let additional = (vectors - data.len()).min(data.len().max(chunk));
+                read_vectors(&mut data, additional, &mut reader)?;
+            }
+        }
+    }
+
+    header.verify(packed_as_bytes(&data)).map_err(invalid_data)?;
+
+    Ok(PackedMxN {
+        rows: header.rows,
+        row_length: header.row_length,
+        vectors_per_row: header.vectors_per_row,
+        data,
+        padding: header.padding,
+    })
+}
+
+impl<T> VecSimd<T>
+where
+    T: Storable,
+{
+    /// Writes this vector in the [binary format](crate::format).
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of `writer`.
+    #[inline]
+    pub fn write_to(&self, writer: impl Write) -> io::Result<()> {
+        write_packed(&self.simd_rows, LAYOUT_VEC, writer)
+    }
+
+    /// Reads a vector in the [binary format](crate::format).
+    ///
+    /// The header isn't trusted. Data of up to 64 MiB is read at once, larger data is read in
+    /// chunks and the allocation doubles with the data actually read, so a corrupt or malicious
+    /// header can't cause a huge allocation. Use [`read_from_trusted`](Self::read_from_trusted)
+    /// to read large data from trusted sources with a single allocation.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of `reader`, and an error of kind [`InvalidData`](io::ErrorKind::InvalidData)
+    /// wrapping an [`Error`] if the header is invalid, doesn't match `T`, or the checksum is wrong.
+    #[inline]
+    pub fn read_from(reader: impl Read) -> io::Result<Self> {
+        Ok(Self {
+            simd_rows: read_packed(LAYOUT_VEC, reader, Source::Untrusted)?,
+        })
+    }
+
+    /// Like [`read_from`](Self::read_from), but checks that `reader` holds all data the header
+    /// claims before allocating it, and then reads it at once.
+    ///
+    /// # Errors
+    ///
+    /// Like [`read_from`](Self::read_from).
+    #[inline]
+    pub fn read_from_seekable(mut reader: impl Read + Seek) -> io::Result<Self> {
+        let remaining = remaining(&mut reader)?;
+
+        Ok(Self {
+            simd_rows: read_packed(LAYOUT_VEC, reader, Source::Remaining(remaining))?,
+        })
+    }
+
+    /// Like [`read_from`](Self::read_from), but trusts the size given in the header.
+    ///
+    /// The data is read with a single allocation and read of that size. The header is checked
+    /// against the checksum only after reading, so a corrupt header can cause a huge allocation.
+    /// Only use this for data from trusted sources.
+    ///
+    /// # Errors
+    ///
+    /// Like [`read_from`](Self::read_from).
+    #[inline]
+    pub fn read_from_trusted(reader: impl Read) -> io::Result<Self> {
+        Ok(Self {
+            simd_rows: read_packed(LAYOUT_VEC, reader, Source::Trusted)?,
+        })
+    }
+}
+
+macro_rules! impl_mat_format {
+    ($strategy:ty, $layout:expr) => {
+        impl<T> MatSimd<T, $strategy>
+        where
+            T: Storable,
+        {
+            /// Writes this matrix in the [binary format](crate::format).
+            ///
+            /// # Errors
+            ///
+            /// Returns any error of `writer`.
+            #[inline]
+            pub fn write_to(&self, writer: impl Write) -> io::Result<()> {
+                write_packed(&self.simd_rows, $layout, writer)
+            }
+
+            /// Reads a matrix in the [binary format](crate::format).
+            ///
+            /// The header isn't trusted. Data of up to 64 MiB is read at once, larger data is read in
+            /// chunks and the allocation doubles with the data actually read, so a corrupt or malicious
+            /// header can't cause a huge allocation. Use [`read_from_trusted`](Self::read_from_trusted)
+            /// to read large data from trusted sources with a single allocation.
+            ///
+            /// # Errors
+            ///
+            /// Returns any error of `reader`, and an error of kind [`InvalidData`](io::ErrorKind::InvalidData)
+            /// wrapping an [`Error`] if the header is invalid, doesn't match `T` or the access strategy,
+            /// or the checksum is wrong.
+            #[inline]
+            pub fn read_from(reader: impl Read) -> io::Result<Self> {
+                read_packed($layout, reader, Source::Untrusted).map(Self::from_packed)
+            }
+
+            /// Like [`read_from`](Self::read_from), but checks that `reader` holds all data the header
+            /// claims before allocating it, and then reads it at once.
+            ///
+            /// # Errors
+            ///
+            /// Like [`read_from`](Self::read_from).
+            #[inline]
+            pub fn read_from_seekable(mut reader: impl Read + Seek) -> io::Result<Self> {
+                let remaining = remaining(&mut reader)?;
+
+                read_packed($layout, reader, Source::Remaining(remaining)).map(Self::from_packed)
+            }
+
+            /// Like [`read_from`](Self::read_from), but trusts the size given in the header.
+            ///
+            /// The data is read with a single allocation and read of that size. The header is checked
+            /// against the checksum only after reading, so a corrupt header can cause a huge allocation.
+            /// Only use this for data from trusted sources.
+            ///
+            /// # Errors
+            ///
+            /// Like [`read_from`](Self::read_from).
+            #[inline]
+            pub fn read_from_trusted(reader: impl Read) -> io::Result<Self> {
+                read_packed($layout, reader, Source::Trusted).map(Self::from_packed)
+            }
+        }
+    };
+}
+
+impl_mat_format!(Rows, LAYOUT_ROWS);
+impl_mat_format!(Columns, LAYOUT_COLUMNS);
+
+#[cfg(test)]
+mod test {
+    use std::io::{Cursor, ErrorKind};
+
+    use super::{checksum, ElementType, HEADER_SIZE};
+    use crate::{
+        arch::{f32x4, f32x8, i64x2, u8x16},
+        Columns, Error, MatSimd, Padding, Rows, VecSimd,
+    };
+
+    fn error_of(result: std::io::Result<impl Sized>) -> Error {
+        let error = result.err().unwrap();
+
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+        *error.get_ref().unwrap().downcast_ref::<Error>().unwrap()
+    }
+
+    #[test]
+    fn roundtrip() {
+        let mut v = VecSimd::<i64x2>::from_slice(&[1, -2, 3]);
+        let mut m = MatSimd::<f32x8, Columns>::with_dimension(9, 3);
+        let mut bytes = Vec::new();
+
+        v.set_padding(Padding::Value(-7));
+        m.column_as_flat_mut(2)[8] = 1.5;
+        m.set_padding(Padding::Unspecified);
+
+        v.write_to(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), HEADER_SIZE + 32);
+
+        m.write_to(&mut bytes).unwrap();
+
+        let mut reader = bytes.as_slice();
+        let v_2 = VecSimd::<i64x2>::read_from(&mut reader).unwrap();
+        let m_2 = MatSimd::<f32x8, Columns>::read_from(&mut reader).unwrap();
+
+        assert!(reader.is_empty());
+
+        let mut reader = Cursor::new(&bytes);
+        reader.set_position(u64::try_from(HEADER_SIZE).unwrap() + 32);
+        assert_eq!(
+            MatSimd::<f32x8, Columns>::read_from_seekable(&mut reader).unwrap().column_as_flat(2)[8].to_bits(),
+            1.5_f32.to_bits()
+        );
+
+        assert_eq!(v_2.flat(), &[1, -2, 3]);
+        assert_eq!(v_2.padding(), Padding::Value(-7));
+        assert_eq!(v_2[1].to_array(), [3, -7]);
+        assert_eq!(m_2.dimension(), (9, 3));
+        assert_eq!(m_2.column_as_flat(2)[8].to_bits(), 1.5_f32.to_bits());
+        assert_eq!(m_2.padding(), Padding::Unspecified);
+    }
+
+    #[test]
+    fn validation() {
+        let mut bytes = Vec::new();
+        MatSimd::<f32x4, Rows>::with_dimension(3, 5).write_to(&mut bytes).unwrap();
+
+        assert_eq!(
+            error_of(MatSimd::<f32x8, Rows>::read_from(bytes.as_slice())),
+            Error::ElementTypeMismatch {
+                expected: (ElementType::F32, 8),
+                actual: (ElementType::F32, 4)
+            }
+        );
+        assert_eq!(
+            error_of(MatSimd::<f32x4, Columns>::read_from(bytes.as_slice())),
+            Error::InvalidHeader { reason: "layout mismatch" }
+        );
+        assert_eq!(
+            error_of(VecSimd::<u8x16>::read_from(&[0_u8; 64][..])),
+            Error::InvalidHeader {
+                reason: "not a simd_aligned file"
+            }
+        );
+
+        let mut corrupt = bytes.clone();
+        corrupt[HEADER_SIZE + 3] ^= 1;
+        assert_eq!(error_of(MatSimd::<f32x4, Rows>::read_from(corrupt.as_slice())), Error::ChecksumMismatch);
+
+        let mut version = bytes.clone();
+        version[8] = 9;
+        assert_eq!(error_of(MatSimd::<f32x4, Rows>::read_from(version.as_slice())), Error::UnsupportedVersion { version: 9 });
+
+        let truncated = MatSimd::<f32x4, Rows>::read_from(&bytes[..bytes.len() - 1]);
+        assert_eq!(truncated.err().unwrap().kind(), ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn huge_header() {
+        let mut bytes = Vec::new();
+        MatSimd::<f32x4, Rows>::with_dimension(3, 5).write_to(&mut bytes).unwrap();
+
+        // Claims 2^40 rows of 2 vectors, but only holds the data of 3 rows.
+        bytes[24..32].copy_from_slice(&(1_u64 << 40).to_le_bytes());
+        bytes[40..48].copy_from_slice(&(1_u64 << 45).to_le_bytes());
+
+        let huge = MatSimd::<f32x4, Rows>::read_from(bytes.as_slice());
+        assert_eq!(huge.err().unwrap().kind(), ErrorKind::UnexpectedEof);
+
+        let huge = MatSimd::<f32x4, Rows>::read_from_seekable(Cursor::new(&bytes));
+        assert_eq!(huge.err().unwrap().kind(), ErrorKind::UnexpectedEof);
+
+        // Claims 2^21 rows, which fits into memory, so trusting the header only fails reading.
+        bytes[24..32].copy_from_slice(&(1_u64 << 21).to_le_bytes());
+        bytes[40..48].copy_from_slice(&(1_u64 << 26).to_le_bytes());
+
+        let large = MatSimd::<f32x4, Rows>::read_from_trusted(bytes.as_slice());
+        assert_eq!(large.err().unwrap().kind(), ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn checksum_covers_all_bytes() {
+        let a = [0_u8; 37];
+        let mut b = a;
+        b[36] = 1;
+
+        assert_ne!(checksum(&[&a]), checksum(&[&b]));
+        assert_ne!(checksum(&[&a[..32], &a[32..]]), checksum(&[&b[..32], &b[32..]]));
+    }
+}
@@ -0,0 +1,179 @@
+//! Strided gathers of one element per row, with hardware gather instructions where available.
+//!
+//! With AVX2, elements of 4 and 8 bytes are loaded by `vpgatherdd` and `vpgatherdq`, 8 or 4 rows
+//! at a time, and stored to the target as one vector. Other element sizes, strides of 1 KiB or
+//! more, the remaining rows and lower levels use a strided copy. There are no scatter
+//! instructions before AVX-512, so the opposite direction is always a strided copy.
+use crate::dispatch::{self, Level};
+
+/// Copies `source[offset + i * stride]` into `target[i]` for all `i`.
+///
+/// # Panics
+///
+/// Panics if an index is out of bounds.
+#[inline]
+pub fn gather<E>(source: &[E], offset: usize, stride: usize, target: &mut [E])
+where
+    E: Copy,
+{
+    gather_at(dispatch::level(), source, offset, stride, target);
+}
+
+/// Like [`gather`], but for the given `level`, which must not exceed [`dispatch::detected`].
+#[inline]
+fn gather_at<E>(level: Level, source: &[E], offset: usize, stride: usize, target: &mut [E])
+where
+    E: Copy,
+{
+    debug_assert!(level <= dispatch::detected());
+
+    let Some(last) = target.len().checked_sub(1) else {
+        return;
+    };
+
+    let end = last.checked_mul(stride).and_then(|x| x.checked_add(offset));
+    assert!(
+        end.is_some_and(|end| end < source.len()),
+        "gather of {} elements with stride {stride} from {offset} out of bounds for {} elements",
+        target.len(),
+        source.len()
+    );
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    // SAFETY: All indices were checked above.
+    let done = unsafe { x86::gather(level, source, offset, stride, target) };
+
+    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+    let done = {
+        _ = level;
+        0
+    };
+
+    for (x, value) in target[done..].iter_mut().zip(source[offset..].iter().step_by(stride).skip(done)) {
+        *x = *value;
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod x86 {
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86 as arch;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64 as arch;
+    use std::mem::size_of;
+
+    use arch::{_mm256_i32gather_epi32, _mm256_i32gather_epi64, _mm256_setr_epi32, _mm256_storeu_si256, _mm_setr_epi32};
+
+    use crate::dispatch::Level;
+
+    /// Above this many bytes between rows every element is a separate cache miss, and the strided
+    /// copy keeps more loads in flight than the gather instructions.
+    const MAX_STRIDE_BYTES: usize = 1024;
+
+    /// Gathers the leading rows of `target` for which there is a vector path at `level`, and
+    /// returns how many that were.
+    ///
+    /// # Safety
+    ///
+    /// `level` must be supported, and `source[offset + i * stride]` must be in bounds for all `i`
+    /// less than `target.len()`.
+    pub unsafe fn gather<E: Copy>(level: Level, source: &[E], offset: usize, stride: usize, target: &mut [E]) -> usize {
+        if level < Level::Avx2 || stride.saturating_mul(size_of::<E>()) >= MAX_STRIDE_BYTES {
+            return 0;
+        }
+
+        // Offsets within a batch of rows are `i32` element counts.
+        let Ok(step) = i32::try_from(stride) else {
+            return 0;
+        };
+
+        let source = source.as_ptr().add(offset);
+        let (count, target) = (target.len(), target.as_mut_ptr());
+
+        match size_of::<E>() {
+            4 if step.checked_mul(7).is_some() => gather_32(source.cast(), stride, step, target.cast(), count),
+            8 if step.checked_mul(3).is_some() => gather_64(source.cast(), stride, step, target.cast(), count),
+            _ => 0,
+        }
+    }
+
+    /// Gathers 8 rows of 4 byte elements at a time.
+    ///
+    /// # Safety
+    ///
+    /// The CPU must support AVX2, `source.add(i * stride)` must be readable for all `i` less than
+    /// `count`, and `7 * step` must not overflow.
+    #[target_feature(enable = "avx2")]
+    unsafe fn gather_32(source: *const i32, stride: usize, step: i32, target: *mut i32, count: usize) -> usize {
+        let offsets = _mm256_setr_epi32(0, step, 2 * step, 3 * step, 4 * step, 5 * step, 6 * step, 7 * step);
+        let batches = count / 8;
+
+        for k in 0..batches {
+            let x = _mm256_i32gather_epi32::<4>(source.add(k * 8 * stride), offsets);
+            _mm256_storeu_si256(target.add(k * 8).cast(), x);
+        }
+
+        batches * 8
+    }
+
+    /// Gathers 4 rows of 8 byte elements at a time.
+    ///
+    /// # Safety
+    ///
+    /// The CPU must support AVX2, `source.add(i * stride)` must be readable for all `i` less than
+    /// `count`, and `3 * step` must not overflow.
+    #[target_feature(enable = "avx2")]
+    unsafe fn gather_64(source: *const i64, stride: usize, step: i32, target: *mut i64, count: usize) -> usize {
+        let offsets = _mm_setr_epi32(0, step, 2 * step, 3 * step);
+        let batches = count / 4;
+
+        for k in 0..batches {
+            let x = _mm256_i32gather_epi64::<8>(source.add(k * 4 * stride), offsets);
+            _mm256_storeu_si256(target.add(k * 4).cast(), x);
+        }
+
+        batches * 4
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::gather_at;
+    use crate::dispatch::{self, Level};
+
+    fn check<E: Copy + PartialEq + std::fmt::Debug>(level: Level, element: impl Fn(usize) -> E) {
+        let source = (0..1000).map(&element).collect::<Vec<_>>();
+
+        for (offset, stride, count) in [(0, 1, 0), (3, 7, 19), (5, 31, 32), (999, 1, 1), (2, 99, 11), (1, 300, 4)] {
+            let mut target = vec![element(0); count];
+            gather_at(level, &source, offset, stride, &mut target);
+
+            assert!(target.iter().enumerate().all(|(i, x)| *x == source[offset + i * stride]));
+        }
+    }
+
+    #[test]
+    fn gathers() {
+        for level in [Level::Baseline, Level::Sse41, Level::Avx2] {
+            if level > dispatch::detected() {
+                continue;
+            }
+
+            #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap, clippy::cast_precision_loss)]
+            {
+                check(level, |i| i as u8);
+                check(level, |i| i as i16);
+                check(level, |i| i as f32);
+                check(level, |i| i as u32);
+                check(level, |i| i as f64);
+                check(level, |i| i as i64);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn out_of_bounds() {
+        gather_at(Level::Baseline, &[1.0_f32; 10], 1, 3, &mut [0.0; 4]);
+    }
+}
@@ -0,0 +1,352 @@
+//! Computational kernels over packed slices of SIMD vectors holding `length` flat elements.
+//!
+//! All kernels only look at the first `length` lanes, so they are correct no matter what the
+//! padding lanes of the last vector contain.
+//!
+//! Sums are computed in a fixed order: the full vectors are split into blocks of [`BLOCK`]
+//! vectors, each block is accumulated lane-wise, and the block results are added lane-wise in
+//! ascending order. Then the lanes of the accumulator are added in ascending order, followed by
+//! the elements of the partial last vector in ascending order. With the `rayon` feature, blocks
+//! are processed in parallel, but combined in the same order, so results are identical.
+//!
+//! The work on each block is done by kernels defined with [`kernel!`], which are compiled once per
+//! instruction set. Public operations read [`dispatch::level`](crate::dispatch::level) once and pass it on, and each block
+//! runs in the variant for that level. See [`dispatch`](crate::dispatch) for what that does and doesn't change.
+use std::ops::{Mul, Range};
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::{
+    dispatch::{kernel, Level},
+    traits::{Float, MaybeSendSync, Simd, SimdFloat},
+};
+
+/// Number of SIMD vectors accumulated together before block results are combined.
+pub const BLOCK: usize = 128;
+
+/// Minimum number of SIMD vectors before work is split across threads.
+#[cfg(feature = "rayon")]
+const PARALLEL_MIN_VECTORS: usize = 16 * 1024;
+
+/// Computes `f` for each block of [`BLOCK`] vectors within `0..vectors` and combines the results
+/// with `init` in ascending order.
+#[inline]
+pub fn fold_blocks<U>(vectors: usize, init: U, combine: impl Fn(U, U) -> U, f: impl Fn(Range<usize>) -> U + MaybeSendSync) -> U
+where
+    U: MaybeSendSync,
+{
+    let block = |i: usize| f(i * BLOCK..vectors.min((i + 1) * BLOCK));
+    let blocks = vectors.div_ceil(BLOCK);
+
+    #[cfg(feature = "rayon")]
+    if vectors >= PARALLEL_MIN_VECTORS {
+        let partials = (0..blocks).into_par_iter().map(block).collect::<Vec<_>>();
+        return partials.into_iter().fold(init, combine);
+    }
+
+    (0..blocks).map(block).fold(init, combine)
+}
+
+/// Computes `f` for each chunk of `chunk_size` vectors of `x`.
+#[inline]
+pub fn map_chunks<T, U>(x: &[T], chunk_size: usize, f: impl Fn(&[T]) -> U + MaybeSendSync) -> Vec<U>
+where
+    T: Simd + MaybeSendSync,
+    U: MaybeSendSync,
+{
+    #[cfg(feature = "rayon")]
+    if x.len() >= PARALLEL_MIN_VECTORS {
+        return x.par_chunks_exact(chunk_size).map(f).collect();
+    }
+
+    x.chunks_exact(chunk_size).map(f).collect()
+}
+
+/// Replaces each `x_i` with `f(x_i, y_i)`.
+#[inline]
+pub fn zip_map<T>(level: Level, x: &mut [T], y: &[T], f: impl Fn(T, T) -> T + MaybeSendSync)
+where
+    T: Simd + MaybeSendSync,
+{
+    let block = |x: &mut [T], y: &[T]| zip_map_block::at(level, x, y, &f);
+
+    #[cfg(feature = "rayon")]
+    if x.len() >= PARALLEL_MIN_VECTORS {
+        x.par_chunks_mut(BLOCK).zip(y.par_chunks(BLOCK)).for_each(|(x, y)| block(x, y));
+        return;
+    }
+
+    block(x, y);
+}
+
+/// Replaces each `x_i` with `f(x_i)`.
+#[inline]
+pub fn map<T>(level: Level, x: &mut [T], f: impl Fn(T) -> T + MaybeSendSync)
+where
+    T: Simd + MaybeSendSync,
+{
+    let block = |x: &mut [T]| map_block::at(level, x, &f);
+
+    #[cfg(feature = "rayon")]
+    if x.len() >= PARALLEL_MIN_VECTORS {
+        x.par_chunks_mut(BLOCK).for_each(block);
+        return;
+    }
+
+    block(x);
+}
+
+kernel! {
+    /// Replaces each `x_i` with `f(x_i, y_i)` within one block.
+    pub fn zip_map_block<T, F>(x: &mut [T], y: &[T], f: &F)
+    where [T: Simd, F: Fn(T, T) -> T]
+    {
+        for (a, b) in x.iter_mut().zip(y) {
+            *a = f(*a, *b);
+        }
+    }
+}
+
+kernel! {
+    /// Replaces each `x_i` with `f(x_i)` within one block.
+    pub fn map_block<T, F>(x: &mut [T], f: &F)
+    where [T: Simd, F: Fn(T) -> T]
+    {
+        for a in x {
+            *a = f(*a);
+        }
+    }
+}
+
+/// Adds the first `lanes` lanes of `x` to `sum` in ascending order.
+///
+/// Lanes are added as splatted vectors, so integer overflow wraps as in SIMD lanes.
+#[inline]
+fn add_lanes<T>(sum: T, x: &T, lanes: usize) -> T
+where
+    T: Simd,
+{
+    x.as_array()[..lanes].iter().fold(sum, |a, b| a + T::splat(*b))
+}
+
+/// Adds the lanes of `x` in ascending order.
+#[inline]
+fn horizontal_sum<T>(x: &T) -> T::Element
+where
+    T: Simd,
+{
+    add_lanes(T::default(), x, T::LANES).as_array()[0]
+}
+
+/// Splits `length` into the number of full vectors and the used lanes of the last vector.
+#[inline]
+const fn full_and_tail<T: Simd>(length: usize) -> (usize, usize) {
+    (length / T::LANES, length % T::LANES)
+}
+
+/// Dot product of the first `length` elements of `x` and `y`.
+#[inline]
+pub fn dot<T>(level: Level, x: &[T], y: &[T], length: usize) -> T::Element
+where
+    T: Simd + Mul<Output = T> + MaybeSendSync,
+{
+    let full = length / T::LANES;
+    let acc = fold_blocks(
+        full,
+        T::default(),
+        |a, b| a + b,
+        |range| dot_accumulate::at(level, T::default(), &x[range.clone()], &y[range]),
+    );
+
+    dot_finish(&acc, x, y, length)
+}
+
+kernel! {
+    /// Adds the lane-wise products of the full vectors `x` and `y` to `acc`.
+    pub fn dot_accumulate<T>(acc: T, x: &[T], y: &[T]) -> T
+    where [T: Simd + Mul<Output = T>]
+    {
+        x.iter().zip(y).fold(acc, |acc, (a, b)| acc + *a * *b)
+    }
+}
+
+/// Reduces an accumulator produced by [`dot_accumulate`] over all full vectors of `x` and `y`,
+/// and adds the products of the used lanes of the partial last vector.
+#[inline]
+pub fn dot_finish<T>(acc: &T, x: &[T], y: &[T], length: usize) -> T::Element
+where
+    T: Simd + Mul<Output = T>,
+{
+    let (full, tail) = full_and_tail::<T>(length);
+    let mut sum = add_lanes(T::default(), acc, T::LANES);
+
+    if tail > 0 {
+        let mut a = x[full];
+        a.as_array_mut()[tail..].fill(T::Element::default());
+
+        sum = add_lanes(sum, &(a * y[full]), tail);
+    }
+
+    sum.as_array()[0]
+}
+
+/// Sum of the absolute values of the first `length` elements of `x`.
+#[inline]
+pub fn asum<T>(level: Level, x: &[T], length: usize) -> T::Element
+where
+    T: SimdFloat + MaybeSendSync,
+{
+    let (full, tail) = full_and_tail::<T>(length);
+    let acc = fold_blocks(full, T::default(), |a, b| a + b, |range| abs_accumulate::at(level, &x[range]));
+
+    let mut sum = horizontal_sum(&acc);
+
+    if tail > 0 {
+        for a in &x[full].as_array()[..tail] {
+            sum = sum + a.abs();
+        }
+    }
+
+    sum
+}
+
+kernel! {
+    /// Lane-wise sum of the absolute values of the full vectors `x`.
+    pub fn abs_accumulate<T>(x: &[T]) -> T
+    where [T: SimdFloat]
+    {
+        x.iter().fold(T::default(), |acc, a| acc + a.abs())
+    }
+}
+
+kernel! {
+    /// Combines the full vectors `x` with the lane-wise `op`, or returns `None` if `x` is empty.
+    pub fn reduce_vectors<T, F>(x: &[T], op: &F) -> Option<T>
+    where [T: Simd, F: Fn(T, T) -> T]
+    {
+        x.iter().copied().reduce(op)
+    }
+}
+
+/// Combines the first `length` elements of `x` with the lane-wise `op`, or returns `None` if
+/// `length` is 0.
+///
+/// The full vectors are combined in blocks as described above, then the used lanes of the partial
+/// last vector are combined with the matching lanes of the result. Finally, the lanes are combined
+/// in ascending order. All steps use `op`, so integer overflow wraps as in SIMD lanes.
+#[inline]
+pub fn reduce<T>(level: Level, x: &[T], length: usize, op: impl Fn(T, T) -> T + MaybeSendSync) -> Option<T::Element>
+where
+    T: Simd + MaybeSendSync,
+{
+    let (full, tail) = full_and_tail::<T>(length);
+    let combine = |a: Option<T>, b: Option<T>| match (a, b) {
+        (Some(a), Some(b)) => Some(op(a, b)),
+        (a, b) => a.or(b),
+    };
+
+    let acc = fold_blocks(full, None, combine, |range| reduce_vectors::at(level, &x[range], &op));
+
+    let (acc, lanes) = match (acc, tail) {
+        (None, 0) => return None,
+        (None, _) => (x[full], tail),
+        (Some(acc), 0) => (acc, T::LANES),
+        (Some(acc), _) => {
+            let mut merged = op(acc, x[full]);
+            merged.as_array_mut()[tail..].copy_from_slice(&acc.as_array()[tail..]);
+            (merged, T::LANES)
+        }
+    };
+
+    // Combine lanes through splatted vectors, so they follow the same semantics as `op`.
+    let rval = acc.as_array()[..lanes].iter().map(|a| T::splat(*a)).reduce(&op)?;
+
+    Some(rval.as_array()[0])
+}
+
+/// Index of the first element with the largest absolute value, or `None` if `length` is 0.
+#[inline]
+pub fn iamax<T>(x: &[T], length: usize) -> Option<usize>
+where
+    T: SimdFloat,
+{
+    let flat = crate::conversion::simd_container_flat_slice(x, length);
+    let (first, rest) = flat.split_first()?;
+    let mut best = (0, first.abs());
+
+    for (i, a) in rest.iter().enumerate() {
+        let a = a.abs();
+
+        if a > best.1 {
+            best = (i + 1, a);
+        }
+    }
+
+    Some(best.0)
+}
+
+/// Computes `y = alpha * x + y` lane-wise over all vectors, including padding.
+#[inline]
+pub fn axpy<T>(level: Level, alpha: T::Element, x: &[T], y: &mut [T])
+where
+    T: Simd + Mul<Output = T> + MaybeSendSync,
+{
+    let alpha = T::splat(alpha);
+
+    zip_map(level, y, x, |b, a| alpha * a + b);
+}
+
+/// Computes `x = alpha * x` lane-wise over all vectors, including padding.
+#[inline]
+pub fn scal<T>(level: Level, alpha: T::Element, x: &mut [T])
+where
+    T: Simd + Mul<Output = T> + MaybeSendSync,
+{
+    let alpha = T::splat(alpha);
+
+    map(level, x, |a| alpha * a);
+}
+
+#[cfg(test)]
+mod test {
+    use super::{dot, dot_accumulate, iamax};
+    use crate::{
+        arch::{f32x4, f32x8, i32x4},
+        dispatch,
+    };
+
+    #[test]
+    fn dot_ignores_padding() {
+        let x = [i32x4::from([1, 2, 3, 4]), i32x4::from([5, 100, 100, 100])];
+        let y = [i32x4::from([1, 1, 1, 1]), i32x4::from([1, 100, 100, 100])];
+
+        let level = dispatch::level();
+
+        assert_eq!(dot(level, &x, &y, 5), 15);
+        assert_eq!(dot(level, &x, &y, 4), 10);
+        assert_eq!(dot(level, &x, &y, 0), 0);
+    }
+
+    #[test]
+    fn iamax_ignores_padding() {
+        let x = [f32x4::from([1.0, -3.0, 3.0, 2.0]), f32x4::from([-1.0, 9.0, 9.0, 9.0])];
+
+        assert_eq!(iamax(&x, 5), Some(1));
+        assert_eq!(iamax(&x, 0), None);
+    }
+
+    #[test]
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    fn variants_are_distinct() {
+        let variants = [
+            dot_accumulate::baseline::<f32x8> as *const (),
+            dot_accumulate::sse41::<f32x8> as *const (),
+            dot_accumulate::avx2::<f32x8> as *const (),
+        ];
+
+        assert_ne!(variants[0], variants[1]);
+        assert_ne!(variants[0], variants[2]);
+        assert_ne!(variants[1], variants[2]);
+    }
+}
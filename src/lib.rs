@@ -93,16 +93,47 @@
 #![allow(clippy::module_name_repetitions)]
 #![allow(clippy::module_inception)]
 
+mod borrowed;
+mod chunks;
 mod conversion;
+mod dual;
+mod error;
+mod gather;
+mod kernels;
 mod mat;
+mod matmul;
+mod matvec;
+mod ops;
 mod packed;
+mod padding;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod reductions;
+#[cfg(feature = "serde")]
+mod serialization;
+mod tiled;
+mod transpose;
 mod vec;
+mod view;
 
 pub mod arch;
+pub mod blas1;
+pub mod dispatch;
+pub mod format;
 pub mod traits;
 
 pub use crate::{
+    borrowed::{MatSimdMut, MatSimdRef, VecSimdMut, VecSimdRef},
+    chunks::{SimdChunk, SimdChunks},
     conversion::{packed_as_flat, packed_as_flat_mut},
-    mat::{AccessStrategy, Columns, MatFlat, MatFlatMut, MatSimd, Rows},
+    dual::{MatDualFlatMut, MatSimdDual},
+    error::Error,
+    mat::{AccessStrategy, Columns, MatFlat, MatFlatIter, MatFlatIterMut, MatFlatMut, MatSimd, Matrix2DFlatIter, Matrix2DFlatIterMut, Matrix2DIter, Matrix2DIterMut, Rows},
+    padding::Padding,
+    tiled::Tiled,
     vec::VecSimd,
+    view::{MatSimdView, VecSimdView},
 };
+
+#[cfg(feature = "mmap")]
+pub use crate::view::MappedFile;
@@ -1,39 +1,108 @@
 use std::{
+    iter::{Enumerate, FusedIterator},
     marker::PhantomData,
+    mem,
     ops::{Index, IndexMut},
 };
 
-use crate::traits::Simd;
+use crate::{traits::Simd, Error, Padding, VecSimd};
 
 use super::{
-    conversion::{simd_container_flat_slice, simd_container_flat_slice_mut},
+    conversion::{packed_as_flat, packed_as_flat_mut, simd_container_flat_slice, simd_container_flat_slice_mut, strategy_flat_offset},
     packed::PackedMxN,
 };
 
-#[doc(hidden)]
-pub trait AccessStrategy {
-    fn flat_to_packed(x: usize, y: usize) -> (usize, usize);
+pub mod private {
+    pub trait Sealed {}
 }
 
-#[derive(Clone, Debug)]
-#[doc(hidden)]
+/// Decides how the elements of a [`MatSimd`] are packed into SIMD vectors.
+///
+/// - [`Rows`] packs each row into its own SIMD vectors, so rows are available as `&[T]`.
+/// - [`Columns`] packs each column into its own SIMD vectors, so columns are available as `&[T]`.
+/// - [`Tiled`](crate::Tiled) packs blocks of rows and SIMD vectors together, so nearby elements in both
+///   directions share cache lines.
+///
+/// All strategies first map a `(row, column)` to a _packed row_ and an index within it, e.g., a
+/// [`Columns`] matrix has one packed row per column. The flat views ([`MatFlat`], [`MatFlatMut`])
+/// work with every strategy.
+///
+/// This trait is sealed, the storage code of this crate relies on the exact layout of each strategy.
+pub trait AccessStrategy: private::Sealed {
+    /// Maps `(row, column)` of a matrix to `(packed row, index)` within the packed row.
+    #[must_use]
+    fn flat_to_packed(row: usize, column: usize) -> (usize, usize);
+
+    /// Maps `(packed row, index)` back to `(row, column)` of a matrix.
+    #[must_use]
+    fn packed_to_flat(packed_row: usize, index: usize) -> (usize, usize);
+
+    /// Number of SIMD vectors stored per packed row of `row_length` elements with `lanes` lanes each.
+    #[doc(hidden)]
+    #[inline]
+    #[must_use]
+    fn vectors_per_row(row_length: usize, lanes: usize) -> Option<usize> {
+        Some(row_length.div_ceil(lanes))
+    }
+
+    /// Number of packed rows stored for `rows` packed rows, including padding rows.
+    #[doc(hidden)]
+    #[inline]
+    #[must_use]
+    fn storage_rows(rows: usize) -> Option<usize> {
+        Some(rows)
+    }
+
+    /// Index of SIMD vector `vector` of packed row `packed_row` in storage.
+    #[doc(hidden)]
+    #[inline]
+    #[must_use]
+    fn vector_index(packed_row: usize, vector: usize, vectors_per_row: usize) -> usize {
+        packed_row * vectors_per_row + vector
+    }
+
+    /// Inverse of [`vector_index`](Self::vector_index), returning `(packed row, vector)`.
+    #[doc(hidden)]
+    #[inline]
+    #[must_use]
+    fn vector_position(index: usize, vectors_per_row: usize) -> (usize, usize) {
+        (index / vectors_per_row, index % vectors_per_row)
+    }
+}
+
+/// Packs each row into SIMD vectors, see [`AccessStrategy`].
+#[derive(Clone, Copy, Debug)]
 pub struct Rows;
 
-#[derive(Clone, Debug)]
-#[doc(hidden)]
+/// Packs each column into SIMD vectors, see [`AccessStrategy`].
+#[derive(Clone, Copy, Debug)]
 pub struct Columns;
 
+impl private::Sealed for Rows {}
+
+impl private::Sealed for Columns {}
+
 impl AccessStrategy for Rows {
     #[inline]
-    fn flat_to_packed(x: usize, y: usize) -> (usize, usize) {
-        (x, y)
+    fn flat_to_packed(row: usize, column: usize) -> (usize, usize) {
+        (row, column)
+    }
+
+    #[inline]
+    fn packed_to_flat(packed_row: usize, index: usize) -> (usize, usize) {
+        (packed_row, index)
     }
 }
 
 impl AccessStrategy for Columns {
     #[inline]
-    fn flat_to_packed(x: usize, y: usize) -> (usize, usize) {
-        (y, x)
+    fn flat_to_packed(row: usize, column: usize) -> (usize, usize) {
+        (column, row)
+    }
+
+    #[inline]
+    fn packed_to_flat(packed_row: usize, index: usize) -> (usize, usize) {
+        (index, packed_row)
     }
 }
 
@@ -59,6 +128,9 @@ impl AccessStrategy for Columns {
 /// // But accessing columns doesn't work, as there is no continuous view in memory.
 /// // m.column(3); --> panic!
 ///
+/// // Instead, copy a single column into a `VecSimd` where needed:
+/// let _ = m.gather_column(3);
+///
 /// // However, you can always get a flat view of the matrix, for "scalar-speed"
 /// // query and update all elements:
 /// let mut m_flat = m.flat_mut();
@@ -81,40 +153,88 @@ where
     O: AccessStrategy,
 {
     /// Creates a new [`MatSimd`] with the given dimension.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension is too large, use [`try_with_dimension`](Self::try_with_dimension) to handle this.
     #[inline]
     #[must_use]
     pub fn with_dimension(width: usize, height: usize) -> Self {
-        let (x, y) = O::flat_to_packed(width, height);
+        Self::try_with_dimension(width, height).unwrap_or_else(|e| panic!("{e}"))
+    }
 
-        Self {
-            simd_rows: PackedMxN::with(T::default(), x, y),
-            phantom: PhantomData,
-        }
+    /// Like [`with_dimension`](Self::with_dimension), but returns an error instead of panicking if the dimension is too large.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use simd_aligned::{Error, MatSimd, arch::f32x4, Rows};
+    ///
+    /// let huge = MatSimd::<f32x4, Rows>::try_with_dimension(1 << 40, 1 << 40);
+    ///
+    /// assert_eq!(huge.unwrap_err(), Error::CapacityOverflow);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::CapacityOverflow`] if the size doesn't fit the address space, and
+    /// [`Error::AllocationFailed`] if the memory can't be allocated.
+    #[inline]
+    pub fn try_with_dimension(width: usize, height: usize) -> Result<Self, Error> {
+        let (rows, row_length) = O::flat_to_packed(width, height);
+        let vectors_per_row = O::vectors_per_row(row_length, T::LANES).ok_or(Error::CapacityOverflow)?;
+        let storage_rows = O::storage_rows(rows).ok_or(Error::CapacityOverflow)?;
+        let mut simd_rows = PackedMxN::try_with_layout(T::default(), rows, row_length, vectors_per_row, storage_rows)?;
+
+        simd_rows.reset_padding_for::<O>();
+
+        Ok(Self::from_packed(simd_rows))
+    }
+
+    /// Wraps packed data that is already laid out for this access strategy.
+    #[inline]
+    pub(crate) const fn from_packed(simd_rows: PackedMxN<T>) -> Self {
+        Self { simd_rows, phantom: PhantomData }
     }
 
     /// Returns the size as (`rows`, `columns`).
     #[must_use]
     pub fn dimension(&self) -> (usize, usize) {
-        O::flat_to_packed(self.simd_rows.rows, self.simd_rows.row_length)
+        O::packed_to_flat(self.simd_rows.rows, self.simd_rows.row_length)
+    }
+
+    /// Returns the current [`Padding`] policy.
+    #[inline]
+    #[must_use]
+    pub const fn padding(&self) -> Padding<T::Element> {
+        self.simd_rows.padding
+    }
+
+    /// Changes the [`Padding`] policy and applies it to all lanes outside the matrix, e.g., the
+    /// last SIMD vector of every row or column.
+    #[inline]
+    pub fn set_padding(&mut self, padding: Padding<T::Element>) {
+        self.simd_rows.padding = padding;
+        self.reset_padding();
+    }
+
+    /// Restores the [`Padding`] policy, e.g., after SIMD vectors were written through `row_mut`.
+    #[inline]
+    pub fn reset_padding(&mut self) {
+        self.simd_rows.reset_padding_for::<O>();
     }
 
     /// Provides a flat, immutable view of the contained data.
     #[inline]
     #[must_use]
-    pub const fn flat(&self) -> MatFlat<'_, T, O> {
-        MatFlat {
-            matrix: self,
-            phantom: PhantomData,
-        }
+    pub fn flat(&self) -> MatFlat<'_, T, O> {
+        MatFlat::new(&self.simd_rows.data, self.simd_rows.rows, self.simd_rows.vectors_per_row, self.simd_rows.row_length)
     }
 
     /// Provides a flat mutable view of the contained data.
     #[inline]
     pub fn flat_mut(&mut self) -> MatFlatMut<'_, T, O> {
-        MatFlatMut {
-            matrix: self,
-            phantom: PhantomData,
-        }
+        MatFlatMut::new(&mut self.simd_rows.data, self.simd_rows.rows, self.simd_rows.vectors_per_row, self.simd_rows.row_length)
     }
 }
 
@@ -129,10 +249,51 @@ where
         &self.simd_rows.data[range]
     }
 
+    /// Returns the SIMD vectors of row `i`, or `None` if there is no such row.
+    #[inline]
+    #[must_use]
+    pub fn get_row(&self, i: usize) -> Option<&[T]> {
+        (i < self.simd_rows.rows).then(|| self.row(i))
+    }
+
+    /// Returns an iterator over the SIMD vectors of all rows.
+    #[inline]
+    #[must_use]
+    pub fn row_iter(&self) -> Matrix2DIter<'_, T, Rows> {
+        Matrix2DIter::new(&self.simd_rows.data, self.simd_rows.rows, self.simd_rows.vectors_per_row, self.simd_rows.row_length)
+    }
+
+    /// Returns an iterator over the SIMD vectors of all rows, allowing modification.
+    #[inline]
+    pub fn row_iter_mut(&mut self) -> Matrix2DIterMut<'_, T, Rows> {
+        Matrix2DIterMut::new(&mut self.simd_rows.data, self.simd_rows.rows, self.simd_rows.vectors_per_row, self.simd_rows.row_length)
+    }
+
+    /// Returns an iterator over the flat views of all rows.
     #[inline]
     #[must_use]
-    pub const fn row_iter(&self) -> Matrix2DIter<'_, T, Rows> {
-        Matrix2DIter { matrix: self, index: 0 }
+    pub fn row_iter_as_flat(&self) -> Matrix2DFlatIter<'_, T, Rows> {
+        Matrix2DFlatIter::new(&self.simd_rows.data, self.simd_rows.rows, self.simd_rows.vectors_per_row, self.simd_rows.row_length)
+    }
+
+    /// Returns an iterator over the flat views of all rows, allowing modification.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use simd_aligned::{MatSimd, arch::f32x4, Rows};
+    ///
+    /// let mut m = MatSimd::<f32x4, Rows>::with_dimension(3, 5);
+    ///
+    /// for (i, row) in m.row_iter_as_flat_mut().enumerate() {
+    ///     row[i] = 1.0;
+    /// }
+    ///
+    /// assert_eq!(m.row_as_flat(2), &[0.0, 0.0, 1.0, 0.0, 0.0]);
+    /// ```
+    #[inline]
+    pub fn row_iter_as_flat_mut(&mut self) -> Matrix2DFlatIterMut<'_, T, Rows> {
+        Matrix2DFlatIterMut::new(&mut self.simd_rows.data, self.simd_rows.rows, self.simd_rows.vectors_per_row, self.simd_rows.row_length)
     }
 
     #[inline]
@@ -154,6 +315,234 @@ where
         let row = self.row_mut(i);
         simd_container_flat_slice_mut(row, length)
     }
+
+    /// Appends a row at the bottom, given as flat slice or [`VecSimd`].
+    ///
+    /// Storage grows amortized like a `Vec`, use [`reserve_rows`](Self::reserve_rows) if the final size is known.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use simd_aligned::{MatSimd, VecSimd, arch::f32x4, Rows};
+    ///
+    /// let mut m = MatSimd::<f32x4, Rows>::with_dimension(0, 3);
+    ///
+    /// m.push_row([1.0, 2.0, 3.0])?;
+    /// m.push_row(VecSimd::<f32x4>::from_slice(&[4.0, 5.0, 6.0]))?;
+    ///
+    /// assert_eq!(m.dimension(), (2, 3));
+    /// assert_eq!(m.row_as_flat(1), &[4.0, 5.0, 6.0]);
+    /// # Ok::<(), simd_aligned::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DimensionMismatch`] if the length of `row` doesn't match the number of columns.
+    #[inline]
+    pub fn push_row(&mut self, row: impl AsRef<[T::Element]>) -> Result<(), Error> {
+        self.insert_row(self.simd_rows.rows, row)
+    }
+
+    /// Inserts a row at `index`, shifting all rows after it down.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DimensionMismatch`] if the length of `row` doesn't match the number of columns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the number of rows.
+    #[inline]
+    pub fn insert_row(&mut self, index: usize, row: impl AsRef<[T::Element]>) -> Result<(), Error> {
+        let row = row.as_ref();
+
+        self.simd_rows.check_row_length(row.len())?;
+        self.simd_rows.insert_row(index, row);
+
+        Ok(())
+    }
+
+    /// Removes and returns the row at `index`, shifting all rows after it up.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn remove_row(&mut self, index: usize) -> VecSimd<T> {
+        VecSimd {
+            simd_rows: self.simd_rows.remove_row(index),
+        }
+    }
+
+    /// Swaps the rows `a` and `b`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds.
+    #[inline]
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        self.simd_rows.swap_rows(a, b);
+    }
+
+    /// Keeps the first `rows` rows and drops the rest. Has no effect if there are fewer rows.
+    #[inline]
+    pub fn truncate_rows(&mut self, rows: usize) {
+        self.simd_rows.truncate_rows(rows);
+    }
+
+    /// Reserves capacity for at least `additional` more rows.
+    #[inline]
+    pub fn reserve_rows(&mut self, additional: usize) {
+        self.simd_rows.reserve_rows(additional);
+    }
+}
+
+impl<T> MatSimd<T, Rows>
+where
+    T: Simd + Default + Clone,
+{
+    /// Returns a copy with `x` and `y` swapped, keeping the access strategy.
+    ///
+    /// The new matrix has dimension (`columns`, `rows`), and element `(y, x)` holds the value of
+    /// element `(x, y)` of this matrix.
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        Self::from_packed(self.simd_rows.transposed())
+    }
+
+    /// Returns a copy of column `i`, although this matrix is optimized for row access.
+    ///
+    /// This reads one element of every row, with AVX2 gather instructions for 4 and 8 byte
+    /// elements if [`dispatch::level`](crate::dispatch::level) allows and the rows are less than
+    /// 1 KiB apart, and a strided copy otherwise.
+    /// It's much cheaper than [`to_columns`](Self::to_columns) if only a few columns are needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use simd_aligned::{MatSimd, arch::f32x4, Rows};
+    ///
+    /// let mut m = MatSimd::<f32x4, Rows>::with_dimension(3, 2);
+    /// m.flat_mut()[(2, 1)] = 1.0;
+    ///
+    /// assert_eq!(m.gather_column(1).flat(), &[0.0, 0.0, 1.0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no column `i`.
+    #[must_use]
+    pub fn gather_column(&self, i: usize) -> VecSimd<T> {
+        let mut rval = VecSimd::with(T::Element::default(), self.simd_rows.rows);
+
+        self.simd_rows.gather(i, rval.flat_mut());
+        rval
+    }
+
+    /// Overwrites column `i` with the content of `column`, the counterpart of [`gather_column`](Self::gather_column).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DimensionMismatch`] if the length of `column` doesn't match the number of rows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no column `i`.
+    pub fn scatter_column(&mut self, i: usize, column: &VecSimd<T>) -> Result<(), Error> {
+        self.simd_rows.check_rows(column.flat().len())?;
+        self.simd_rows.scatter(i, column.flat());
+        Ok(())
+    }
+
+    /// Returns a copy with the same dimension and content, optimized for column access.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use simd_aligned::{MatSimd, arch::f32x4, Rows};
+    ///
+    /// let mut m = MatSimd::<f32x4, Rows>::with_dimension(10, 5);
+    /// m.flat_mut()[(2, 4)] = 42.0;
+    ///
+    /// let c = m.to_columns();
+    ///
+    /// assert_eq!(c.flat()[(2, 4)], 42.0);
+    /// assert_eq!(c.column_as_flat(4)[2], 42.0);
+    /// ```
+    #[must_use]
+    pub fn to_columns(&self) -> MatSimd<T, Columns> {
+        MatSimd {
+            simd_rows: self.simd_rows.transposed(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> MatSimd<T, Columns>
+where
+    T: Simd + Default + Clone,
+{
+    /// Returns a copy with `x` and `y` swapped, keeping the access strategy.
+    ///
+    /// The new matrix has dimension (`columns`, `rows`), and element `(y, x)` holds the value of
+    /// element `(x, y)` of this matrix.
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        Self::from_packed(self.simd_rows.transposed())
+    }
+
+    /// Returns a copy of row `i`, although this matrix is optimized for column access.
+    ///
+    /// This reads one element of every column, with AVX2 gather instructions for 4 and 8 byte
+    /// elements if [`dispatch::level`](crate::dispatch::level) allows and the columns are less than
+    /// 1 KiB apart, and a strided copy otherwise.
+    /// It's much cheaper than [`to_rows`](Self::to_rows) if only a few rows are needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use simd_aligned::{MatSimd, arch::f32x4, Columns};
+    ///
+    /// let mut m = MatSimd::<f32x4, Columns>::with_dimension(3, 2);
+    /// m.flat_mut()[(1, 0)] = 1.0;
+    ///
+    /// assert_eq!(m.gather_row(1).flat(), &[1.0, 0.0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no row `i`.
+    #[must_use]
+    pub fn gather_row(&self, i: usize) -> VecSimd<T> {
+        let mut rval = VecSimd::with(T::Element::default(), self.simd_rows.rows);
+
+        self.simd_rows.gather(i, rval.flat_mut());
+        rval
+    }
+
+    /// Overwrites row `i` with the content of `row`, the counterpart of [`gather_row`](Self::gather_row).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DimensionMismatch`] if the length of `row` doesn't match the number of columns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no row `i`.
+    pub fn scatter_row(&mut self, i: usize, row: &VecSimd<T>) -> Result<(), Error> {
+        self.simd_rows.check_rows(row.flat().len())?;
+        self.simd_rows.scatter(i, row.flat());
+        Ok(())
+    }
+
+    /// Returns a copy with the same dimension and content, optimized for row access.
+    #[must_use]
+    pub fn to_rows(&self) -> MatSimd<T, Rows> {
+        MatSimd {
+            simd_rows: self.simd_rows.transposed(),
+            phantom: PhantomData,
+        }
+    }
 }
 
 impl<T> MatSimd<T, Columns>
@@ -167,10 +556,37 @@ where
         &self.simd_rows.data[range]
     }
 
+    /// Returns the SIMD vectors of column `i`, or `None` if there is no such column.
+    #[inline]
+    #[must_use]
+    pub fn get_column(&self, i: usize) -> Option<&[T]> {
+        (i < self.simd_rows.rows).then(|| self.column(i))
+    }
+
+    /// Returns an iterator over the SIMD vectors of all columns.
     #[inline]
     #[must_use]
-    pub const fn column_iter(&self) -> Matrix2DIter<'_, T, Columns> {
-        Matrix2DIter { matrix: self, index: 0 }
+    pub fn column_iter(&self) -> Matrix2DIter<'_, T, Columns> {
+        Matrix2DIter::new(&self.simd_rows.data, self.simd_rows.rows, self.simd_rows.vectors_per_row, self.simd_rows.row_length)
+    }
+
+    /// Returns an iterator over the SIMD vectors of all columns, allowing modification.
+    #[inline]
+    pub fn column_iter_mut(&mut self) -> Matrix2DIterMut<'_, T, Columns> {
+        Matrix2DIterMut::new(&mut self.simd_rows.data, self.simd_rows.rows, self.simd_rows.vectors_per_row, self.simd_rows.row_length)
+    }
+
+    /// Returns an iterator over the flat views of all columns.
+    #[inline]
+    #[must_use]
+    pub fn column_iter_as_flat(&self) -> Matrix2DFlatIter<'_, T, Columns> {
+        Matrix2DFlatIter::new(&self.simd_rows.data, self.simd_rows.rows, self.simd_rows.vectors_per_row, self.simd_rows.row_length)
+    }
+
+    /// Returns an iterator over the flat views of all columns, allowing modification.
+    #[inline]
+    pub fn column_iter_as_flat_mut(&mut self) -> Matrix2DFlatIterMut<'_, T, Columns> {
+        Matrix2DFlatIterMut::new(&mut self.simd_rows.data, self.simd_rows.rows, self.simd_rows.vectors_per_row, self.simd_rows.row_length)
     }
 
     #[inline]
@@ -194,6 +610,71 @@ where
         let column = self.column_mut(i);
         simd_container_flat_slice_mut(column, length)
     }
+
+    /// Appends a column at the right, given as flat slice or [`VecSimd`].
+    ///
+    /// Storage grows amortized like a `Vec`, use [`reserve_columns`](Self::reserve_columns) if the final size is known.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DimensionMismatch`] if the length of `column` doesn't match the number of rows.
+    #[inline]
+    pub fn push_column(&mut self, column: impl AsRef<[T::Element]>) -> Result<(), Error> {
+        self.insert_column(self.simd_rows.rows, column)
+    }
+
+    /// Inserts a column at `index`, shifting all columns after it to the right.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DimensionMismatch`] if the length of `column` doesn't match the number of rows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the number of columns.
+    #[inline]
+    pub fn insert_column(&mut self, index: usize, column: impl AsRef<[T::Element]>) -> Result<(), Error> {
+        let column = column.as_ref();
+
+        self.simd_rows.check_row_length(column.len())?;
+        self.simd_rows.insert_row(index, column);
+
+        Ok(())
+    }
+
+    /// Removes and returns the column at `index`, shifting all columns after it to the left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn remove_column(&mut self, index: usize) -> VecSimd<T> {
+        VecSimd {
+            simd_rows: self.simd_rows.remove_row(index),
+        }
+    }
+
+    /// Swaps the columns `a` and `b`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds.
+    #[inline]
+    pub fn swap_columns(&mut self, a: usize, b: usize) {
+        self.simd_rows.swap_rows(a, b);
+    }
+
+    /// Keeps the first `columns` columns and drops the rest. Has no effect if there are fewer columns.
+    #[inline]
+    pub fn truncate_columns(&mut self, columns: usize) {
+        self.simd_rows.truncate_rows(columns);
+    }
+
+    /// Reserves capacity for at least `additional` more columns.
+    #[inline]
+    pub fn reserve_columns(&mut self, additional: usize) {
+        self.simd_rows.reserve_rows(additional);
+    }
 }
 
 /// Produced by [`MatSimd::flat`], this allow for flat matrix access.
@@ -202,8 +683,11 @@ where
     T: Simd + Default + Clone + 'a,
     A: AccessStrategy + 'a,
 {
-    matrix: &'a MatSimd<T, A>,
-    phantom: PhantomData<A>, // Do we actually need this / is there a better way?
+    data: &'a [T],
+    rows: usize,
+    vectors_per_row: usize,
+    row_length: usize,
+    phantom: PhantomData<A>,
 }
 
 /// Provided by [`MatSimd::flat_mut`], this allow for flat, mutable matrix access.
@@ -212,8 +696,159 @@ where
     T: Simd + Default + Clone + 'a,
     A: AccessStrategy + 'a,
 {
-    matrix: &'a mut MatSimd<T, A>,
-    phantom: PhantomData<A>, // Do we actually need this / is there a better way?
+    data: &'a mut [T],
+    rows: usize,
+    vectors_per_row: usize,
+    row_length: usize,
+    phantom: PhantomData<A>,
+}
+
+impl<'a, T, A> MatFlat<'a, T, A>
+where
+    T: Simd + Default + Clone,
+    A: AccessStrategy,
+{
+    /// Creates a flat view of `rows` packed rows with `vectors_per_row` vectors holding `row_length` elements each.
+    #[inline]
+    pub(crate) const fn new(data: &'a [T], rows: usize, vectors_per_row: usize, row_length: usize) -> Self {
+        Self {
+            data,
+            rows,
+            vectors_per_row,
+            row_length,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the size as (`rows`, `columns`).
+    #[inline]
+    #[must_use]
+    pub fn dimension(&self) -> (usize, usize) {
+        A::packed_to_flat(self.rows, self.row_length)
+    }
+
+    /// Returns the element at `(row, column)`, or `None` if it is outside the matrix.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use simd_aligned::{MatSimd, arch::f32x4, Rows};
+    ///
+    /// let m = MatSimd::<f32x4, Rows>::with_dimension(2, 3);
+    ///
+    /// assert_eq!(m.flat().get((1, 2)), Some(&0.0));
+    /// assert_eq!(m.flat().get((1, 3)), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn get(&self, index: (usize, usize)) -> Option<&T::Element> {
+        let (row, x) = A::flat_to_packed(index.0, index.1);
+        let offset = strategy_flat_offset::<T, A>(self.rows, self.row_length, self.vectors_per_row, row, x)?;
+
+        packed_as_flat(self.data).get(offset)
+    }
+
+    /// Returns an iterator over all elements along with their `(row, column)`, in memory order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use simd_aligned::{MatSimd, arch::f32x4, Columns};
+    ///
+    /// let mut m = MatSimd::<f32x4, Columns>::with_dimension(2, 3);
+    /// m.flat_mut()[(1, 2)] = 5.0;
+    ///
+    /// let found = m.flat().iter().find(|(_, x)| **x == 5.0).map(|(index, _)| index);
+    ///
+    /// assert_eq!(found, Some((1, 2)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn iter(&self) -> MatFlatIter<'_, T, A> {
+        MatFlatIter::new(self.data, self.rows, self.vectors_per_row, self.row_length)
+    }
+}
+
+impl<'a, T, A> MatFlatMut<'a, T, A>
+where
+    T: Simd + Default + Clone,
+    A: AccessStrategy,
+{
+    /// Creates a flat mutable view of `rows` packed rows with `vectors_per_row` vectors holding `row_length` elements each.
+    #[inline]
+    pub(crate) const fn new(data: &'a mut [T], rows: usize, vectors_per_row: usize, row_length: usize) -> Self {
+        Self {
+            data,
+            rows,
+            vectors_per_row,
+            row_length,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the size as (`rows`, `columns`).
+    #[inline]
+    #[must_use]
+    pub fn dimension(&self) -> (usize, usize) {
+        A::packed_to_flat(self.rows, self.row_length)
+    }
+
+    /// Returns the element at `(row, column)`, or `None` if it is outside the matrix.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, index: (usize, usize)) -> Option<&T::Element> {
+        let (row, x) = A::flat_to_packed(index.0, index.1);
+        let offset = strategy_flat_offset::<T, A>(self.rows, self.row_length, self.vectors_per_row, row, x)?;
+
+        packed_as_flat(self.data).get(offset)
+    }
+
+    /// Returns the element at `(row, column)` for modification, or `None` if it is outside the matrix.
+    #[inline]
+    pub fn get_mut(&mut self, index: (usize, usize)) -> Option<&mut T::Element> {
+        let (row, x) = A::flat_to_packed(index.0, index.1);
+        let offset = strategy_flat_offset::<T, A>(self.rows, self.row_length, self.vectors_per_row, row, x)?;
+
+        packed_as_flat_mut(self.data).get_mut(offset)
+    }
+
+    /// Returns an iterator over all elements along with their `(row, column)`, in memory order.
+    #[inline]
+    #[must_use]
+    pub fn iter(&self) -> MatFlatIter<'_, T, A> {
+        MatFlatIter::new(self.data, self.rows, self.vectors_per_row, self.row_length)
+    }
+
+    /// Returns an iterator over all elements along with their `(row, column)`, in memory order, allowing modification.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use simd_aligned::{MatSimd, arch::f32x4, Rows};
+    ///
+    /// let mut m = MatSimd::<f32x4, Rows>::with_dimension(2, 3);
+    ///
+    /// for ((row, column), x) in m.flat_mut().iter_mut() {
+    ///     *x = (row * 10 + column) as f32;
+    /// }
+    ///
+    /// assert_eq!(m.row_as_flat(1), &[10.0, 11.0, 12.0]);
+    /// ```
+    #[inline]
+    pub fn iter_mut(&mut self) -> MatFlatIterMut<'_, T, A> {
+        MatFlatIterMut::new(self.data, self.rows, self.vectors_per_row, self.row_length)
+    }
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+#[allow(clippy::redundant_pub_crate)] // Stays out of the public API even if `mat` is ever re-exported.
+pub(crate) fn out_of_bounds(index: (usize, usize), dimension: (usize, usize)) -> ! {
+    panic!(
+        "index ({}, {}) out of bounds for matrix with {} rows and {} columns",
+        index.0, index.1, dimension.0, dimension.1
+    )
 }
 
 impl<T, A> Index<(usize, usize)> for MatFlat<'_, T, A>
@@ -224,11 +859,9 @@ where
     type Output = T::Element;
 
     #[inline]
+    #[track_caller]
     fn index(&self, index: (usize, usize)) -> &Self::Output {
-        let (row, x) = A::flat_to_packed(index.0, index.1);
-        let row_slice = self.matrix.simd_rows.row_as_flat(row);
-
-        &row_slice[x]
+        self.get(index).unwrap_or_else(|| out_of_bounds(index, self.dimension()))
     }
 }
 
@@ -240,11 +873,9 @@ where
     type Output = T::Element;
 
     #[inline]
+    #[track_caller]
     fn index(&self, index: (usize, usize)) -> &Self::Output {
-        let (row, x) = A::flat_to_packed(index.0, index.1);
-        let row_slice = self.matrix.simd_rows.row_as_flat(row);
-
-        &row_slice[x]
+        self.get(index).unwrap_or_else(|| out_of_bounds(index, self.dimension()))
     }
 }
 
@@ -254,51 +885,379 @@ where
     A: AccessStrategy,
 {
     #[inline]
+    #[track_caller]
     fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
-        let (row, x) = A::flat_to_packed(index.0, index.1);
-        let row_slice = self.matrix.simd_rows.row_as_flat_mut(row);
+        let dimension = self.dimension();
+        self.get_mut(index).unwrap_or_else(|| out_of_bounds(index, dimension))
+    }
+}
 
-        &mut row_slice[x]
+impl<'a, T, A> IntoIterator for &'a MatFlat<'_, T, A>
+where
+    T: Simd + Default + Clone,
+    A: AccessStrategy,
+{
+    type Item = ((usize, usize), &'a T::Element);
+    type IntoIter = MatFlatIter<'a, T, A>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, A> IntoIterator for &'a MatFlatMut<'_, T, A>
+where
+    T: Simd + Default + Clone,
+    A: AccessStrategy,
+{
+    type Item = ((usize, usize), &'a T::Element);
+    type IntoIter = MatFlatIter<'a, T, A>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
     }
 }
 
-/// Basic iterator struct to go over matrix
+impl<'a, T, A> IntoIterator for &'a mut MatFlatMut<'_, T, A>
+where
+    T: Simd + Default + Clone,
+    A: AccessStrategy,
+{
+    type Item = ((usize, usize), &'a mut T::Element);
+    type IntoIter = MatFlatIterMut<'a, T, A>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// Iterator over the SIMD vectors of all rows (or columns) of a matrix.
 #[derive(Clone, Debug)]
 pub struct Matrix2DIter<'a, T, O>
 where
     T: Simd + Default + Clone + 'a,
     O: AccessStrategy + 'a,
 {
-    /// Reference to the matrix we iterate over.
-    pub(crate) matrix: &'a MatSimd<T, O>,
+    data: &'a [T],
+    rows: usize,
+    vectors_per_row: usize,
+    row_length: usize,
+    phantom: PhantomData<O>,
+}
+
+/// Iterator over the SIMD vectors of all rows (or columns) of a matrix, allowing modification.
+#[derive(Debug)]
+pub struct Matrix2DIterMut<'a, T, O>
+where
+    T: Simd + Default + Clone + 'a,
+    O: AccessStrategy + 'a,
+{
+    data: &'a mut [T],
+    rows: usize,
+    vectors_per_row: usize,
+    row_length: usize,
+    phantom: PhantomData<O>,
+}
+
+/// Iterator over the flat views of all rows (or columns) of a matrix.
+#[derive(Clone, Debug)]
+pub struct Matrix2DFlatIter<'a, T, O>
+where
+    T: Simd + Default + Clone + 'a,
+    O: AccessStrategy + 'a,
+{
+    data: &'a [T],
+    rows: usize,
+    vectors_per_row: usize,
+    row_length: usize,
+    phantom: PhantomData<O>,
+}
 
-    /// Current index of vector iteration.
-    pub(crate) index: usize,
+/// Iterator over the flat views of all rows (or columns) of a matrix, allowing modification.
+#[derive(Debug)]
+pub struct Matrix2DFlatIterMut<'a, T, O>
+where
+    T: Simd + Default + Clone + 'a,
+    O: AccessStrategy + 'a,
+{
+    data: &'a mut [T],
+    rows: usize,
+    vectors_per_row: usize,
+    row_length: usize,
+    phantom: PhantomData<O>,
 }
 
-impl<'a, T, O> Iterator for Matrix2DIter<'a, T, O>
+impl<'a, T, O> Matrix2DIter<'a, T, O>
 where
     T: Simd + Default + Clone,
     O: AccessStrategy,
 {
-    type Item = &'a [T];
+    #[inline]
+    pub(crate) fn new(data: &'a [T], rows: usize, vectors_per_row: usize, row_length: usize) -> Self {
+        // Storage can hold more rows than the matrix, and rows of length 0 hold no vectors at all.
+        Self {
+            data: &data[..rows * vectors_per_row],
+            rows,
+            vectors_per_row,
+            row_length,
+            phantom: PhantomData,
+        }
+    }
+
+    #[inline]
+    const fn item(chunk: &'a [T], _: usize) -> &'a [T] {
+        chunk
+    }
+}
+
+impl<'a, T, O> Matrix2DIterMut<'a, T, O>
+where
+    T: Simd + Default + Clone,
+    O: AccessStrategy,
+{
+    #[inline]
+    pub(crate) fn new(data: &'a mut [T], rows: usize, vectors_per_row: usize, row_length: usize) -> Self {
+        Self {
+            data: &mut data[..rows * vectors_per_row],
+            rows,
+            vectors_per_row,
+            row_length,
+            phantom: PhantomData,
+        }
+    }
+
+    #[inline]
+    const fn item(chunk: &'a mut [T], _: usize) -> &'a mut [T] {
+        chunk
+    }
+}
+
+impl<'a, T, O> Matrix2DFlatIter<'a, T, O>
+where
+    T: Simd + Default + Clone,
+    O: AccessStrategy,
+{
+    #[inline]
+    pub(crate) fn new(data: &'a [T], rows: usize, vectors_per_row: usize, row_length: usize) -> Self {
+        Self {
+            data: &data[..rows * vectors_per_row],
+            rows,
+            vectors_per_row,
+            row_length,
+            phantom: PhantomData,
+        }
+    }
+
+    #[inline]
+    const fn item(chunk: &'a [T], row_length: usize) -> &'a [T::Element] {
+        simd_container_flat_slice(chunk, row_length)
+    }
+}
 
+impl<'a, T, O> Matrix2DFlatIterMut<'a, T, O>
+where
+    T: Simd + Default + Clone,
+    O: AccessStrategy,
+{
     #[inline]
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.index >= self.matrix.simd_rows.rows {
-            None
-        } else {
-            let range = self.matrix.simd_rows.range_for_row(self.index);
-            self.index += 1;
-            Some(&self.matrix.simd_rows.data[range])
+    pub(crate) fn new(data: &'a mut [T], rows: usize, vectors_per_row: usize, row_length: usize) -> Self {
+        Self {
+            data: &mut data[..rows * vectors_per_row],
+            rows,
+            vectors_per_row,
+            row_length,
+            phantom: PhantomData,
         }
     }
+
+    #[inline]
+    const fn item(chunk: &'a mut [T], row_length: usize) -> &'a mut [T::Element] {
+        simd_container_flat_slice_mut(chunk, row_length)
+    }
 }
 
+macro_rules! impl_matrix_iter {
+    ($iter:ident, $item:ty, $split_at:ident) => {
+        impl<'a, T, O> Iterator for $iter<'a, T, O>
+        where
+            T: Simd + Default + Clone,
+            O: AccessStrategy,
+        {
+            type Item = $item;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.rows == 0 {
+                    return None;
+                }
+
+                let (row, rest) = mem::take(&mut self.data).$split_at(self.vectors_per_row);
+                self.data = rest;
+                self.rows -= 1;
+
+                Some(Self::item(row, self.row_length))
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.rows, Some(self.rows))
+            }
+        }
+
+        impl<'a, T, O> DoubleEndedIterator for $iter<'a, T, O>
+        where
+            T: Simd + Default + Clone,
+            O: AccessStrategy,
+        {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                if self.rows == 0 {
+                    return None;
+                }
+
+                self.rows -= 1;
+                let (rest, row) = mem::take(&mut self.data).$split_at(self.rows * self.vectors_per_row);
+                self.data = rest;
+
+                Some(Self::item(row, self.row_length))
+            }
+        }
+
+        impl<T, O> ExactSizeIterator for $iter<'_, T, O>
+        where
+            T: Simd + Default + Clone,
+            O: AccessStrategy,
+        {
+        }
+
+        impl<T, O> FusedIterator for $iter<'_, T, O>
+        where
+            T: Simd + Default + Clone,
+            O: AccessStrategy,
+        {
+        }
+    };
+}
+
+impl_matrix_iter!(Matrix2DIter, &'a [T], split_at);
+impl_matrix_iter!(Matrix2DIterMut, &'a mut [T], split_at_mut);
+impl_matrix_iter!(Matrix2DFlatIter, &'a [T::Element], split_at);
+impl_matrix_iter!(Matrix2DFlatIterMut, &'a mut [T::Element], split_at_mut);
+
+/// Iterator over all elements of a matrix along with their `(row, column)`, produced by [`MatFlat::iter`].
+#[derive(Clone, Debug)]
+pub struct MatFlatIter<'a, T, A>
+where
+    T: Simd + Default + Clone + 'a,
+    A: AccessStrategy + 'a,
+{
+    elements: Enumerate<std::slice::Iter<'a, T::Element>>,
+    rows: usize,
+    vectors_per_row: usize,
+    row_length: usize,
+    remaining: usize,
+    phantom: PhantomData<A>,
+}
+
+/// Iterator over all elements of a matrix along with their `(row, column)`, allowing modification.
+///
+/// Produced by [`MatFlatMut::iter_mut`].
+#[derive(Debug)]
+pub struct MatFlatIterMut<'a, T, A>
+where
+    T: Simd + Default + Clone + 'a,
+    A: AccessStrategy + 'a,
+{
+    elements: Enumerate<std::slice::IterMut<'a, T::Element>>,
+    rows: usize,
+    vectors_per_row: usize,
+    row_length: usize,
+    remaining: usize,
+    phantom: PhantomData<A>,
+}
+
+macro_rules! impl_flat_iter {
+    ($iter:ident, $data:ty, $flat:ident, $item:ty) => {
+        impl<'a, T, A> $iter<'a, T, A>
+        where
+            T: Simd + Default + Clone,
+            A: AccessStrategy,
+        {
+            #[inline]
+            fn new(data: $data, rows: usize, vectors_per_row: usize, row_length: usize) -> Self {
+                Self {
+                    elements: $flat(data).into_iter().enumerate(),
+                    rows,
+                    vectors_per_row,
+                    row_length,
+                    remaining: rows * row_length,
+                    phantom: PhantomData,
+                }
+            }
+        }
+
+        impl<'a, T, A> Iterator for $iter<'a, T, A>
+        where
+            T: Simd + Default + Clone,
+            A: AccessStrategy,
+        {
+            type Item = ((usize, usize), $item);
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.remaining == 0 {
+                    return None;
+                }
+
+                loop {
+                    let (i, value) = self.elements.next()?;
+                    let (row, vector) = A::vector_position(i / T::LANES, self.vectors_per_row);
+                    let x = vector * T::LANES + i % T::LANES;
+
+                    // Skips padding lanes, vectors and rows.
+                    if row < self.rows && x < self.row_length {
+                        self.remaining -= 1;
+                        return Some((A::packed_to_flat(row, x), value));
+                    }
+                }
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.remaining, Some(self.remaining))
+            }
+        }
+
+        impl<T, A> ExactSizeIterator for $iter<'_, T, A>
+        where
+            T: Simd + Default + Clone,
+            A: AccessStrategy,
+        {
+        }
+
+        impl<T, A> FusedIterator for $iter<'_, T, A>
+        where
+            T: Simd + Default + Clone,
+            A: AccessStrategy,
+        {
+        }
+    };
+}
+
+impl_flat_iter!(MatFlatIter, &'a [T], packed_as_flat, &'a T::Element);
+impl_flat_iter!(MatFlatIterMut, &'a mut [T], packed_as_flat_mut, &'a mut T::Element);
+
 #[cfg(test)]
+#[allow(clippy::semicolon_if_nothing_returned, clippy::similar_names)] // Keeps the original tests as written.
 mod test {
     use super::{Columns, MatSimd, Rows};
-    use crate::arch::f32x4;
+    use crate::{
+        arch::{f32x4, i32x4},
+        Error, Padding, VecSimd,
+    };
 
     #[test]
     fn allocation_size() {
@@ -364,6 +1323,224 @@ mod test {
         assert_eq!(count, 10);
     }
 
+    #[test]
+    fn checked_access() {
+        let r = MatSimd::<i32x4, Rows>::try_with_dimension(3, 5).unwrap();
+        let c = MatSimd::<i32x4, Columns>::try_with_dimension(3, 5).unwrap();
+        let mut empty = MatSimd::<i32x4, Rows>::with_dimension(4, 0);
+
+        assert_eq!(r.get_row(2).map(<[i32x4]>::len), Some(2));
+        assert_eq!(r.get_row(3), None);
+        assert_eq!(c.get_column(4).map(<[i32x4]>::len), Some(1));
+        assert_eq!(c.get_column(5), None);
+
+        assert_eq!(r.flat().get((2, 4)), Some(&0));
+        assert_eq!(r.flat().get((3, 0)), None);
+        assert_eq!(r.flat().get((0, 5)), None);
+        assert_eq!(c.flat().get((2, 4)), Some(&0));
+        assert_eq!(c.flat().get((0, 5)), None);
+        assert_eq!(empty.flat_mut().get((3, 0)), None);
+        assert_eq!(MatSimd::<i32x4, Columns>::try_with_dimension(usize::MAX, 2).err(), Some(Error::CapacityOverflow));
+    }
+
+    #[test]
+    fn gather_scatter() {
+        let mut r = MatSimd::<i32x4, Rows>::with_dimension(5, 6);
+        let mut c = MatSimd::<i32x4, Columns>::with_dimension(6, 5);
+
+        for ((row, column), x) in &mut r.flat_mut() {
+            *x = i32::try_from(row * 10 + column).unwrap();
+        }
+
+        for ((row, column), x) in &mut c.flat_mut() {
+            *x = i32::try_from(row * 10 + column).unwrap();
+        }
+
+        assert_eq!(r.gather_column(5).flat(), &[5, 15, 25, 35, 45]);
+        assert_eq!(c.gather_row(5).flat(), &[50, 51, 52, 53, 54]);
+
+        let v = VecSimd::<i32x4>::from_slice(&[-1, -2, -3, -4, -5]);
+
+        r.scatter_column(4, &v).unwrap();
+        c.scatter_row(0, &v).unwrap();
+
+        assert_eq!(r.row_as_flat(4), &[40, 41, 42, 43, -5, 45]);
+        assert_eq!(r.row(4)[1].to_array(), [-5, 45, 0, 0]);
+        assert_eq!(c.column_as_flat(2), &[-3, 12, 22, 32, 42, 52]);
+        assert_eq!(r.scatter_column(0, &VecSimd::from_slice(&[1, 2])), Err(Error::DimensionMismatch { expected: 5, actual: 2 }));
+    }
+
+    #[test]
+    fn flat_access() {
+        let mut m = MatSimd::<i32x4, Columns>::with_dimension(3, 5);
+        let mut flat = m.flat_mut();
+
+        *flat.get_mut((2, 4)).unwrap() = 7;
+
+        assert_eq!(flat.dimension(), (3, 5));
+        assert_eq!(flat.get_mut((3, 4)), None);
+        assert_eq!(flat.get_mut((2, 5)), None);
+
+        for ((row, column), x) in &mut flat {
+            *x += i32::try_from(row * 10 + column).unwrap();
+        }
+
+        let flat = m.flat();
+        let elements = flat.iter().collect::<Vec<_>>();
+
+        assert_eq!(flat.iter().len(), 15);
+        assert_eq!(elements[..4], [((0, 0), &0), ((1, 0), &10), ((2, 0), &20), ((0, 1), &1)]);
+        assert_eq!(elements.last(), Some(&((2, 4), &31)));
+        assert_eq!(m.column_as_flat(4), &[4, 14, 31]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index (1, 3) out of bounds for matrix with 2 rows and 3 columns")]
+    fn flat_out_of_bounds() {
+        let m = MatSimd::<i32x4, Rows>::with_dimension(2, 3);
+
+        // Column 3 lies in the padding of the first SIMD vector.
+        let _ = m.flat()[(1, 3)];
+    }
+
+    #[test]
+    #[should_panic(expected = "index (2, 0) out of bounds for matrix with 2 rows and 0 columns")]
+    fn flat_mut_out_of_bounds() {
+        let mut m = MatSimd::<i32x4, Columns>::with_dimension(2, 0);
+
+        m.flat_mut()[(2, 0)] = 1;
+    }
+
+    #[test]
+    fn padding() {
+        let mut m = MatSimd::<i32x4, Rows>::with_dimension(2, 3);
+
+        m.set_padding(Padding::Value(9));
+        m.row_mut(1)[0] = i32x4::splat(1);
+
+        assert_eq!(m.row(0)[0].to_array(), [0, 0, 0, 9]);
+        assert_eq!(m.row(1)[0].to_array(), [1, 1, 1, 1]);
+
+        m.reset_padding();
+
+        assert_eq!(m.row(1)[0].to_array(), [1, 1, 1, 9]);
+    }
+
+    #[test]
+    fn layout_conversion() {
+        let mut m = MatSimd::<i32x4, Rows>::with_dimension(7, 10);
+
+        for x in 0..7 {
+            for y in 0..10 {
+                m.flat_mut()[(x, y)] = i32::try_from(x * 100 + y).unwrap();
+            }
+        }
+
+        let c = m.to_columns();
+        let r = c.to_rows();
+        let t = m.transpose();
+        let t_c = c.transpose();
+
+        assert_eq!(c.dimension(), (7, 10));
+        assert_eq!(t.dimension(), (10, 7));
+        assert_eq!(t_c.dimension(), (10, 7));
+
+        for x in 0..7 {
+            for y in 0..10 {
+                assert_eq!(c.flat()[(x, y)], m.flat()[(x, y)]);
+                assert_eq!(r.flat()[(x, y)], m.flat()[(x, y)]);
+                assert_eq!(t.flat()[(y, x)], m.flat()[(x, y)]);
+                assert_eq!(t_c.flat()[(y, x)], m.flat()[(x, y)]);
+            }
+        }
+    }
+
+    #[test]
+    fn grow_shrink() {
+        let mut m_r = MatSimd::<i32x4, Rows>::with_dimension(0, 5);
+        let mut m_c = MatSimd::<i32x4, Columns>::with_dimension(5, 0);
+
+        m_r.reserve_rows(3);
+        m_c.reserve_columns(3);
+
+        for i in 0..3 {
+            m_r.push_row([i; 5]).unwrap();
+            m_c.push_column(VecSimd::<i32x4>::from_slice(&[i; 5])).unwrap();
+        }
+
+        m_r.insert_row(0, vec![9; 5]).unwrap();
+        m_c.insert_column(0, [9; 5]).unwrap();
+        m_r.swap_rows(1, 3);
+        m_c.swap_columns(1, 3);
+
+        assert_eq!(m_r.dimension(), (4, 5));
+        assert_eq!(m_c.dimension(), (5, 4));
+        assert_eq!(m_r.push_row([1; 4]), Err(Error::DimensionMismatch { expected: 5, actual: 4 }));
+        assert_eq!(m_c.push_column([1; 6]), Err(Error::DimensionMismatch { expected: 5, actual: 6 }));
+
+        assert_eq!(m_r.remove_row(0).flat(), &[9; 5]);
+        assert_eq!(m_c.remove_column(0).flat(), &[9; 5]);
+        assert_eq!(m_r.row_as_flat(0), &[2; 5]);
+        assert_eq!(m_c.column_as_flat(2), &[0; 5]);
+
+        m_r.truncate_rows(1);
+        m_c.truncate_columns(1);
+
+        assert_eq!(m_r.dimension(), (1, 5));
+        assert_eq!(m_c.dimension(), (5, 1));
+        assert_eq!(m_r.row(0)[1].to_array(), [2, 0, 0, 0]);
+    }
+
+    #[test]
+    fn iterators() {
+        let mut m_r = MatSimd::<i32x4, Rows>::with_dimension(3, 5);
+        let mut m_c = MatSimd::<i32x4, Columns>::with_dimension(5, 3);
+
+        for (i, row) in m_r.row_iter_mut().enumerate() {
+            row[1] = i32x4::splat(i32::try_from(i).unwrap());
+        }
+
+        for (i, column) in m_c.column_iter_as_flat_mut().rev().enumerate() {
+            column.fill(i32::try_from(i).unwrap());
+        }
+
+        m_r.reset_padding();
+
+        assert_eq!(m_r.row_iter().len(), 3);
+        assert_eq!(m_r.row_iter().next_back().unwrap()[1].to_array(), [2, 0, 0, 0]);
+        assert_eq!(m_r.row_iter_as_flat().nth(1).unwrap(), &[0, 0, 0, 0, 1]);
+        assert_eq!(m_c.column_iter().len(), 3);
+        assert_eq!(m_c.column_iter_as_flat().map(<[i32]>::len).sum::<usize>(), 15);
+        assert_eq!(m_c.column_as_flat(0), &[2; 5]);
+
+        let mut iter = m_r.row_iter_as_flat_mut();
+        iter.by_ref().for_each(drop);
+
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn empty_iterators() {
+        let mut m_r = MatSimd::<f32x4, Rows>::with_dimension(3, 0);
+        let mut m_c = MatSimd::<f32x4, Columns>::with_dimension(0, 3);
+
+        assert_eq!(m_r.row_iter().len(), 3);
+        assert_eq!(m_r.row_iter().rev().count(), 3);
+        assert_eq!(m_r.row_iter_mut().len(), 3);
+        assert_eq!(m_r.row_iter_as_flat().collect::<Vec<_>>(), [&[] as &[f32]; 3]);
+        assert_eq!(m_r.row_iter_as_flat_mut().map(|x| x.len()).collect::<Vec<_>>(), [0; 3]);
+        assert_eq!(m_c.column_iter().len(), 3);
+        assert_eq!(m_c.column_iter_mut().next_back().map(|x| x.len()), Some(0));
+        assert_eq!(m_c.column_iter_as_flat().count(), 3);
+        assert_eq!(m_c.column_iter_as_flat_mut().len(), 3);
+
+        let m_r = MatSimd::<f32x4, Rows>::with_dimension(0, 3);
+        let m_c = MatSimd::<f32x4, Columns>::with_dimension(3, 0);
+
+        assert_eq!(m_r.row_iter().len(), 0);
+        assert_eq!(m_c.column_iter_as_flat().next(), None);
+    }
+
     #[test]
     fn flattened() {
         let mut m_1_5_r = MatSimd::<f32x4, Rows>::with_dimension(1, 5);
@@ -0,0 +1,184 @@
+use std::ops::Mul;
+
+use crate::{
+    dispatch::{self, kernel},
+    kernels,
+    traits::Simd,
+    Columns, Error, MatSimd, Rows,
+};
+
+/// Number of rows of the left and columns of the right operand processed together.
+const TILE: usize = 16;
+
+impl<T> MatSimd<T, Rows>
+where
+    T: Simd + Mul<Output = T>,
+{
+    /// Computes the matrix product `A * B` of this `m x k` matrix and a `k x n` matrix.
+    ///
+    /// Since rows of `A` and columns of `B` are both SIMD aligned, each element of the result
+    /// is a SIMD dot product. The computation is blocked in tiles so that the involved rows and
+    /// columns stay in cache. Each element is accumulated in the same order as a single
+    /// [`mul_vec`](MatSimd::mul_vec) dot product, so the result doesn't depend on the blocking.
+    ///
+    /// Works for all types with SIMD addition and multiplication, e.g., `f32x__`, `f64x__`, and
+    /// all integer types except `i8x__` and `u8x__`. Integer products wrap on overflow within
+    /// SIMD lanes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use simd_aligned::{MatSimd, arch::f64x2, Columns, Rows};
+    ///
+    /// let mut a = MatSimd::<f64x2, Rows>::with_dimension(1, 3);
+    /// let mut b = MatSimd::<f64x2, Columns>::with_dimension(3, 2);
+    ///
+    /// a.row_as_flat_mut(0).copy_from_slice(&[1.0, 2.0, 3.0]);
+    /// b.column_as_flat_mut(0).copy_from_slice(&[1.0, 1.0, 1.0]);
+    /// b.column_as_flat_mut(1).copy_from_slice(&[1.0, 0.0, 2.0]);
+    ///
+    /// let c = a.matmul(&b)?;
+    ///
+    /// assert_eq!(c.row_as_flat(0), &[6.0, 7.0]);
+    /// # Ok::<(), simd_aligned::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DimensionMismatch`] if the number of columns of `A` doesn't match the number of rows of `B`.
+    pub fn matmul(&self, rhs: &MatSimd<T, Columns>) -> Result<Self, Error> {
+        self.simd_rows.check_row_length(rhs.simd_rows.row_length)?;
+
+        let mut rval = Self::with_dimension(self.simd_rows.rows, rhs.simd_rows.rows);
+        multiply::at(dispatch::level(), self, rhs, &mut rval);
+
+        Ok(rval)
+    }
+}
+
+kernel! {
+    /// Computes the product `c = a * b` of matching matrices tile by tile.
+    pub fn multiply<T>(a: &MatSimd<T, Rows>, b: &MatSimd<T, Columns>, c: &mut MatSimd<T, Rows>)
+    where [T: Simd + Mul<Output = T>]
+    {
+        let length = a.simd_rows.row_length;
+        let (m, n) = (a.simd_rows.rows, b.simd_rows.rows);
+        let full = length / T::LANES;
+
+        let mut acc = [T::default(); TILE * TILE];
+
+        for i_0 in (0..m).step_by(TILE) {
+            let i_n = TILE.min(m - i_0);
+
+            for j_0 in (0..n).step_by(TILE) {
+                let j_n = TILE.min(n - j_0);
+
+                acc.fill(T::default());
+
+                // Blocks along the shared axis match those of `kernels::dot`, so each block is
+                // accumulated separately and added to the running sum.
+                for v_0 in (0..full).step_by(kernels::BLOCK) {
+                    let v_range = v_0..kernels::BLOCK.min(full - v_0) + v_0;
+
+                    for i in 0..i_n {
+                        let x = &a.row(i_0 + i)[v_range.clone()];
+
+                        for j in 0..j_n {
+                            let y = &b.column(j_0 + j)[v_range.clone()];
+                            acc[i * TILE + j] = acc[i * TILE + j] + kernels::dot_accumulate::body(T::default(), x, y);
+                        }
+                    }
+                }
+
+                for i in 0..i_n {
+                    let x = a.row(i_0 + i);
+
+                    for j in 0..j_n {
+                        let y = b.column(j_0 + j);
+                        c.row_as_flat_mut(i_0 + i)[j_0 + j] = kernels::dot_finish(&acc[i * TILE + j], x, y, length);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::ops::Mul;
+
+    use crate::{
+        arch::{f32x8, f64x2, f64x4, i32x4, u16x8},
+        traits::Simd,
+        Columns, Error, MatSimd, Padding, Rows,
+    };
+
+    fn check<T>(rows: usize, depth: usize, columns: usize, element: impl Fn(usize) -> T::Element)
+    where
+        T: Simd + Mul<Output = T>,
+        T::Element: PartialEq,
+    {
+        let mut a = MatSimd::<T, Rows>::with_dimension(rows, depth);
+        let mut b = MatSimd::<T, Columns>::with_dimension(depth, columns);
+
+        b.set_padding(Padding::Value(element(7)));
+
+        for x in 0..rows {
+            for y in 0..depth {
+                a.flat_mut()[(x, y)] = element(x * 3 + y);
+            }
+        }
+
+        for x in 0..depth {
+            for y in 0..columns {
+                b.flat_mut()[(x, y)] = element(x + y * 5);
+            }
+        }
+
+        let c = a.matmul(&b).unwrap();
+        let (a, b, c) = (a.flat(), b.flat(), c.flat());
+
+        for x in 0..rows {
+            for y in 0..columns {
+                let mut expected = T::Element::default();
+
+                for i in 0..depth {
+                    expected = expected + a[(x, i)] * b[(i, y)];
+                }
+
+                assert!(c[(x, y)] == expected);
+            }
+        }
+    }
+
+    #[test]
+    fn against_naive() {
+        for (m, k, n) in [(1, 1, 1), (3, 5, 2), (17, 9, 33), (20, 300, 18), (3, 1100, 5)] {
+            check::<f64x2>(m, k, n, |x| f64::from(u8::try_from(x % 7).unwrap()));
+            check::<f64x4>(m, k, n, |x| f64::from(u8::try_from(x % 5).unwrap()));
+            check::<f32x8>(m, k, n, |x| f32::from(u8::try_from(x % 3).unwrap()));
+            check::<i32x4>(m, k, n, |x| i32::try_from(x % 11).unwrap() - 5);
+            check::<u16x8>(m, k, n, |x| u16::try_from(x % 4).unwrap());
+        }
+    }
+
+    #[test]
+    fn wraps() {
+        let mut a = MatSimd::<i32x4, Rows>::with_dimension(1, 5);
+        let mut b = MatSimd::<i32x4, Columns>::with_dimension(5, 2);
+
+        a.row_as_flat_mut(0).fill(i32::MAX);
+        b.column_as_flat_mut(0).fill(2);
+        b.column_as_flat_mut(1).copy_from_slice(&[0, 0, 0, 0, 2]);
+
+        assert_eq!(a.matmul(&b).unwrap().row_as_flat(0), &[-10, -2]);
+    }
+
+    #[test]
+    fn dimension_mismatch() {
+        let a = MatSimd::<f32x8, Rows>::with_dimension(2, 3);
+        let b = MatSimd::<f32x8, Columns>::with_dimension(4, 2);
+
+        assert_eq!(a.matmul(&b).err(), Some(Error::DimensionMismatch { expected: 3, actual: 4 }));
+    }
+}
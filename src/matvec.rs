@@ -0,0 +1,203 @@
+use crate::{
+    dispatch::{self, Level},
+    kernels,
+    traits::{MaybeSendSync, SimdFloat},
+    Columns, Error, MatSimd, Rows, VecSimd,
+};
+
+/// Computes `y = beta * y`, treating `beta == 0` as overwrite so `NaN` in `y` doesn't propagate.
+#[inline]
+fn scale_output<T: SimdFloat + MaybeSendSync>(level: Level, beta: T::Element, y: &mut VecSimd<T>) {
+    if beta == T::Element::default() {
+        y.simd_rows.data.fill(T::default());
+    } else {
+        kernels::scal(level, beta, &mut y.simd_rows.data);
+    }
+}
+
+impl<T> MatSimd<T, Rows>
+where
+    T: SimdFloat + MaybeSendSync,
+{
+    /// Computes the matrix-vector product `A * x`.
+    ///
+    /// The length of `x` must match the number of columns, the result has one element per row.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use simd_aligned::{MatSimd, VecSimd, arch::f32x4, Rows};
+    ///
+    /// let mut m = MatSimd::<f32x4, Rows>::with_dimension(2, 3);
+    /// m.row_as_flat_mut(0).copy_from_slice(&[1.0, 2.0, 3.0]);
+    /// m.row_as_flat_mut(1).copy_from_slice(&[4.0, 5.0, 6.0]);
+    ///
+    /// let x = VecSimd::<f32x4>::from_slice(&[1.0, 1.0, 2.0]);
+    /// let y = m.mul_vec(&x)?;
+    ///
+    /// assert_eq!(y.flat(), &[9.0, 21.0]);
+    /// # Ok::<(), simd_aligned::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DimensionMismatch`] if the length of `x` doesn't match the number of columns.
+    #[inline]
+    pub fn mul_vec(&self, x: &VecSimd<T>) -> Result<VecSimd<T>, Error> {
+        self.simd_rows.check_row_length(x.simd_rows.row_length)?;
+
+        let mut y = VecSimd::with_default(self.simd_rows.rows);
+        self.gemv_unchecked(dispatch::level(), x, &mut y, |_, dot| dot);
+
+        Ok(y)
+    }
+
+    /// Computes `y = alpha * A * x + beta * y`.
+    ///
+    /// If `beta` is zero, `y` is overwritten and its previous content ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DimensionMismatch`] if the length of `x` doesn't match the number of
+    /// columns, or the length of `y` doesn't match the number of rows.
+    #[inline]
+    pub fn gemv(&self, alpha: T::Element, x: &VecSimd<T>, beta: T::Element, y: &mut VecSimd<T>) -> Result<(), Error> {
+        self.simd_rows.check_row_length(x.simd_rows.row_length)?;
+        self.simd_rows.check_rows(y.simd_rows.row_length)?;
+
+        let level = dispatch::level();
+
+        scale_output(level, beta, y);
+        self.gemv_unchecked(level, x, y, |y_i, dot| y_i + alpha * dot);
+
+        Ok(())
+    }
+
+    /// Updates each `y_i` with `f(y_i, dot(row_i, x))`.
+    #[inline]
+    fn gemv_unchecked(&self, level: Level, x: &VecSimd<T>, y: &mut VecSimd<T>, f: impl Fn(T::Element, T::Element) -> T::Element) {
+        let length = self.simd_rows.row_length;
+
+        for (y_i, row) in y.flat_mut().iter_mut().zip(self.row_iter()) {
+            *y_i = f(*y_i, kernels::dot(level, row, &x.simd_rows.data, length));
+        }
+
+        y.simd_rows.reset_padding();
+    }
+}
+
+impl<T> MatSimd<T, Columns>
+where
+    T: SimdFloat + MaybeSendSync,
+{
+    /// Computes the matrix-vector product `A * x` by accumulating the columns of `A` scaled by `x`.
+    ///
+    /// The length of `x` must match the number of columns, the result has one element per row.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DimensionMismatch`] if the length of `x` doesn't match the number of columns.
+    #[inline]
+    pub fn mul_vec(&self, x: &VecSimd<T>) -> Result<VecSimd<T>, Error> {
+        self.simd_rows.check_rows(x.simd_rows.row_length)?;
+
+        let mut y = VecSimd::with_default(self.simd_rows.row_length);
+        self.gemv_unchecked(dispatch::level(), x, &mut y, |x_j| x_j);
+
+        Ok(y)
+    }
+
+    /// Computes `y = alpha * A * x + beta * y` by accumulating the columns of `A` scaled by `alpha * x`.
+    ///
+    /// If `beta` is zero, `y` is overwritten and its previous content ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DimensionMismatch`] if the length of `x` doesn't match the number of
+    /// columns, or the length of `y` doesn't match the number of rows.
+    #[inline]
+    pub fn gemv(&self, alpha: T::Element, x: &VecSimd<T>, beta: T::Element, y: &mut VecSimd<T>) -> Result<(), Error> {
+        self.simd_rows.check_rows(x.simd_rows.row_length)?;
+        self.simd_rows.check_row_length(y.simd_rows.row_length)?;
+
+        let level = dispatch::level();
+
+        scale_output(level, beta, y);
+        self.gemv_unchecked(level, x, y, |x_j| alpha * x_j);
+
+        Ok(())
+    }
+
+    /// Adds each `column_j` scaled by `f(x_j)` to `y`.
+    #[inline]
+    fn gemv_unchecked(&self, level: Level, x: &VecSimd<T>, y: &mut VecSimd<T>, f: impl Fn(T::Element) -> T::Element) {
+        for (x_j, column) in x.flat().iter().zip(self.column_iter()) {
+            kernels::axpy(level, f(*x_j), column, &mut y.simd_rows.data);
+        }
+
+        y.simd_rows.reset_padding();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{arch::f64x4, Columns, Error, MatSimd, Padding, Rows, VecSimd};
+
+    fn matrices() -> (MatSimd<f64x4, Rows>, MatSimd<f64x4, Columns>) {
+        let mut m_r = MatSimd::<f64x4, Rows>::with_dimension(3, 5);
+        let mut m_c = MatSimd::<f64x4, Columns>::with_dimension(3, 5);
+
+        m_r.set_padding(Padding::Value(f64::NAN));
+        m_c.set_padding(Padding::Value(f64::NAN));
+
+        for x in 0..3 {
+            for y in 0..5 {
+                let value = f64::from(u8::try_from(x * 5 + y).unwrap());
+                m_r.flat_mut()[(x, y)] = value;
+                m_c.flat_mut()[(x, y)] = value;
+            }
+        }
+
+        (m_r, m_c)
+    }
+
+    #[test]
+    fn mul_vec() {
+        let (m_r, m_c) = matrices();
+        let x = VecSimd::<f64x4>::from_slice(&[1.0, 0.0, 2.0, 0.0, 1.0]);
+
+        assert_eq!(m_r.mul_vec(&x).unwrap().flat(), &[8.0, 28.0, 48.0]);
+        assert_eq!(m_c.mul_vec(&x).unwrap().flat(), &[8.0, 28.0, 48.0]);
+    }
+
+    #[test]
+    fn gemv() {
+        let (m_r, m_c) = matrices();
+        let x = VecSimd::<f64x4>::from_slice(&[1.0, 0.0, 2.0, 0.0, 1.0]);
+
+        let mut y_r = VecSimd::<f64x4>::from_slice(&[1.0, 2.0, 3.0]);
+        let mut y_c = y_r.clone();
+
+        m_r.gemv(2.0, &x, 3.0, &mut y_r).unwrap();
+        m_c.gemv(2.0, &x, 3.0, &mut y_c).unwrap();
+
+        assert_eq!(y_r.flat(), &[19.0, 62.0, 105.0]);
+        assert_eq!(y_c.flat(), &[19.0, 62.0, 105.0]);
+
+        let mut y_nan = VecSimd::<f64x4>::with(f64::NAN, 3);
+        m_r.gemv(1.0, &x, 0.0, &mut y_nan).unwrap();
+
+        assert_eq!(y_nan.flat(), &[8.0, 28.0, 48.0]);
+    }
+
+    #[test]
+    fn dimension_mismatch() {
+        let (m_r, m_c) = matrices();
+        let x = VecSimd::<f64x4>::with(1.0, 3);
+        let mut y = VecSimd::<f64x4>::with(1.0, 5);
+
+        assert_eq!(m_r.mul_vec(&x).err(), Some(Error::DimensionMismatch { expected: 5, actual: 3 }));
+        assert_eq!(m_c.mul_vec(&x).err(), Some(Error::DimensionMismatch { expected: 5, actual: 3 }));
+        assert_eq!(m_r.gemv(1.0, &y.clone(), 1.0, &mut y), Err(Error::DimensionMismatch { expected: 3, actual: 5 }));
+    }
+}
@@ -0,0 +1,295 @@
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+use crate::{
+    dispatch, kernels,
+    traits::{MaybeSendSync, Simd},
+    Error, VecSimd,
+};
+
+/// Applies `f` to all SIMD vectors of `lhs` and `rhs` pairwise, storing the result in `lhs`.
+#[inline]
+fn zip_apply<T>(lhs: &mut VecSimd<T>, rhs: &VecSimd<T>, f: impl Fn(T, T) -> T + MaybeSendSync)
+where
+    T: Simd + Default + Copy + MaybeSendSync,
+{
+    assert_eq!(
+        lhs.simd_rows.row_length, rhs.simd_rows.row_length,
+        "VecSimd length mismatch: left has {} elements, right has {}",
+        lhs.simd_rows.row_length, rhs.simd_rows.row_length
+    );
+
+    kernels::zip_map(dispatch::level(), &mut lhs.simd_rows.data, &rhs.simd_rows.data, f);
+    lhs.simd_rows.reset_padding();
+}
+
+/// Applies `f` to all SIMD vectors of `lhs` and the splatted `rhs`, storing the result in `lhs`.
+#[inline]
+fn broadcast_apply<T>(lhs: &mut VecSimd<T>, rhs: T::Element, f: impl Fn(T, T) -> T + MaybeSendSync)
+where
+    T: Simd + Default + Copy + MaybeSendSync,
+{
+    let rhs = T::splat(rhs);
+
+    kernels::map(dispatch::level(), &mut lhs.simd_rows.data, |a| f(a, rhs));
+    lhs.simd_rows.reset_padding();
+}
+
+macro_rules! impl_vec_op {
+    ($op:ident, $f:ident, $op_assign:ident, $f_assign:ident, $checked:ident, $symbol:literal) => {
+        impl<T> VecSimd<T>
+        where
+            T: Simd + Default + Copy + MaybeSendSync + $op<Output = T>,
+        {
+            #[doc = concat!("Computes `self ", $symbol, " rhs` element-wise into a new vector, or returns an error if the lengths differ.")]
+            ///
+            /// # Errors
+            ///
+            /// Returns [`Error::DimensionMismatch`] if `rhs` doesn't have the length of `self`.
+            #[inline]
+            pub fn $checked(&self, rhs: &VecSimd<T>) -> Result<VecSimd<T>, Error> {
+                self.simd_rows.check_row_length(rhs.simd_rows.row_length)?;
+
+                let mut rval = self.clone();
+                zip_apply(&mut rval, rhs, $op::$f);
+                Ok(rval)
+            }
+        }
+
+        impl<T> $op_assign<&VecSimd<T>> for VecSimd<T>
+        where
+            T: Simd + Default + Copy + MaybeSendSync + $op<Output = T>,
+        {
+            #[doc = concat!("Computes `self ", $symbol, "= rhs` element-wise.")]
+            ///
+            /// # Panics
+            ///
+            #[doc = concat!("Panics if the lengths differ, use [`VecSimd::", stringify!($checked), "`] to get an error instead.")]
+            #[inline]
+            fn $f_assign(&mut self, rhs: &VecSimd<T>) {
+                zip_apply(self, rhs, $op::$f);
+            }
+        }
+
+        impl<T> $op_assign<VecSimd<T>> for VecSimd<T>
+        where
+            T: Simd + Default + Copy + MaybeSendSync + $op<Output = T>,
+        {
+            #[doc = concat!("Computes `self ", $symbol, "= rhs` element-wise.")]
+            ///
+            /// # Panics
+            ///
+            #[doc = concat!("Panics if the lengths differ, use [`VecSimd::", stringify!($checked), "`] to get an error instead.")]
+            #[inline]
+            fn $f_assign(&mut self, rhs: VecSimd<T>) {
+                zip_apply(self, &rhs, $op::$f);
+            }
+        }
+
+        impl<T> $op<&VecSimd<T>> for VecSimd<T>
+        where
+            T: Simd + Default + Copy + MaybeSendSync + $op<Output = T>,
+        {
+            type Output = VecSimd<T>;
+
+            #[doc = concat!("Computes `self ", $symbol, " rhs` element-wise.")]
+            ///
+            /// # Panics
+            ///
+            #[doc = concat!("Panics if the lengths differ, use [`VecSimd::", stringify!($checked), "`] to get an error instead.")]
+            #[inline]
+            fn $f(mut self, rhs: &VecSimd<T>) -> Self::Output {
+                zip_apply(&mut self, rhs, $op::$f);
+                self
+            }
+        }
+
+        impl<T> $op<VecSimd<T>> for VecSimd<T>
+        where
+            T: Simd + Default + Copy + MaybeSendSync + $op<Output = T>,
+        {
+            type Output = VecSimd<T>;
+
+            #[doc = concat!("Computes `self ", $symbol, " rhs` element-wise.")]
+            ///
+            /// # Panics
+            ///
+            #[doc = concat!("Panics if the lengths differ, use [`VecSimd::", stringify!($checked), "`] to get an error instead.")]
+            #[inline]
+            fn $f(mut self, rhs: VecSimd<T>) -> Self::Output {
+                zip_apply(&mut self, &rhs, $op::$f);
+                self
+            }
+        }
+
+        impl<T> $op<VecSimd<T>> for &VecSimd<T>
+        where
+            T: Simd + Default + Copy + MaybeSendSync + $op<Output = T>,
+        {
+            type Output = VecSimd<T>;
+
+            #[doc = concat!("Computes `self ", $symbol, " rhs` element-wise.")]
+            ///
+            /// # Panics
+            ///
+            #[doc = concat!("Panics if the lengths differ, use [`VecSimd::", stringify!($checked), "`] to get an error instead.")]
+            #[inline]
+            fn $f(self, mut rhs: VecSimd<T>) -> Self::Output {
+                // Reuse the allocation of `rhs`, the result follows the padding policy of `self`.
+                rhs.simd_rows.padding = self.simd_rows.padding;
+                zip_apply(&mut rhs, self, |b, a| $op::$f(a, b));
+                rhs
+            }
+        }
+
+        impl<T> $op<&VecSimd<T>> for &VecSimd<T>
+        where
+            T: Simd + Default + Copy + MaybeSendSync + $op<Output = T>,
+        {
+            type Output = VecSimd<T>;
+
+            #[doc = concat!("Computes `self ", $symbol, " rhs` element-wise.")]
+            ///
+            /// # Panics
+            ///
+            #[doc = concat!("Panics if the lengths differ, use [`VecSimd::", stringify!($checked), "`] to get an error instead.")]
+            #[inline]
+            fn $f(self, rhs: &VecSimd<T>) -> Self::Output {
+                let mut rval = self.clone();
+                zip_apply(&mut rval, rhs, $op::$f);
+                rval
+            }
+        }
+    };
+}
+
+// Scalar operations can't be implemented generically over `T` as `Op<T::Element>` would
+// conflict with `Op<VecSimd<T>>`, so we implement them for each element type instead. Which SIMD
+// types support an operation is left to the `Op<Output = T>` bound, and `impl_simd!` checks that
+// all registered types get them.
+macro_rules! impl_scalar_op {
+    ($op:ident, $f:ident, $op_assign:ident, $f_assign:ident => $($element:ty),*) => {
+        $(
+            impl<T> $op_assign<$element> for VecSimd<T>
+            where
+                T: Simd<Element = $element> + Default + Copy + MaybeSendSync + $op<Output = T>,
+            {
+                #[inline]
+                fn $f_assign(&mut self, rhs: $element) {
+                    broadcast_apply(self, rhs, $op::$f);
+                }
+            }
+
+            impl<T> $op<$element> for VecSimd<T>
+            where
+                T: Simd<Element = $element> + Default + Copy + MaybeSendSync + $op<Output = T>,
+            {
+                type Output = Self;
+
+                #[inline]
+                fn $f(mut self, rhs: $element) -> Self::Output {
+                    broadcast_apply(&mut self, rhs, $op::$f);
+                    self
+                }
+            }
+
+            impl<T> $op<$element> for &VecSimd<T>
+            where
+                T: Simd<Element = $element> + Default + Copy + MaybeSendSync + $op<Output = T>,
+            {
+                type Output = VecSimd<T>;
+
+                #[inline]
+                fn $f(self, rhs: $element) -> Self::Output {
+                    let mut rval = self.clone();
+                    broadcast_apply(&mut rval, rhs, $op::$f);
+                    rval
+                }
+            }
+        )*
+    };
+}
+
+impl_vec_op!(Add, add, AddAssign, add_assign, checked_add, "+");
+impl_vec_op!(Sub, sub, SubAssign, sub_assign, checked_sub, "-");
+impl_vec_op!(Mul, mul, MulAssign, mul_assign, checked_mul, "*");
+impl_vec_op!(Div, div, DivAssign, div_assign, checked_div, "/");
+
+impl_scalar_op!(Add, add, AddAssign, add_assign => u8, i8, u16, i16, u32, i32, u64, i64, f32, f64);
+impl_scalar_op!(Sub, sub, SubAssign, sub_assign => u8, i8, u16, i16, u32, i32, u64, i64, f32, f64);
+impl_scalar_op!(Mul, mul, MulAssign, mul_assign => u8, i8, u16, i16, u32, i32, u64, i64, f32, f64);
+impl_scalar_op!(Div, div, DivAssign, div_assign => u8, i8, u16, i16, u32, i32, u64, i64, f32, f64);
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        arch::{f32x4, i32x4, u8x16},
+        Error, Padding, VecSimd,
+    };
+
+    #[test]
+    fn vector_ops() {
+        let a = VecSimd::<i32x4>::from_slice(&[1, 2, 3, 4, 5]);
+        let b = VecSimd::<i32x4>::from_slice(&[5, 4, 3, 2, 1]);
+
+        assert_eq!((&a + &b).flat(), &[6, 6, 6, 6, 6]);
+        assert_eq!((&a - &b).flat(), &[-4, -2, 0, 2, 4]);
+        assert_eq!((a.clone() * &b).flat(), &[5, 8, 9, 8, 5]);
+
+        let mut c = a;
+        c += &b;
+        c *= b;
+
+        assert_eq!(c.flat(), &[30, 24, 18, 12, 6]);
+        assert_eq!(c[1].to_array(), [6, 0, 0, 0]);
+
+        let mut d = VecSimd::<i32x4>::from_slice(&[1, 1, 1, 1, 1]);
+        d.set_padding(Padding::Value(-1));
+
+        assert_eq!((&c - d.clone()).flat(), &[29, 23, 17, 11, 5]);
+        assert_eq!((&c - d.clone())[1].to_array(), [5, 0, 0, 0]);
+        assert_eq!((&d - c.clone())[1].to_array(), [-5, -1, -1, -1]);
+    }
+
+    #[test]
+    fn checked_ops() {
+        let a = VecSimd::<f32x4>::from_slice(&[1.0, 2.0, 3.0]);
+        let b = VecSimd::<f32x4>::from_slice(&[2.0, 2.0, 2.0]);
+
+        assert_eq!(a.checked_div(&b).unwrap().flat(), &[0.5, 1.0, 1.5]);
+        assert_eq!(a.checked_sub(&b).unwrap().flat(), &[-1.0, 0.0, 1.0]);
+        assert_eq!(a.checked_add(&VecSimd::with(0.0, 4)).err(), Some(Error::DimensionMismatch { expected: 3, actual: 4 }));
+    }
+
+    #[test]
+    fn scalar_ops() {
+        let mut a = VecSimd::<u8x16>::from_slice(&[1, 2, 3]);
+
+        a += 2;
+        a = &a - 1;
+
+        assert_eq!(a.flat(), &[2, 3, 4]);
+        assert_eq!(a[0].to_array()[3], 0);
+    }
+
+    #[test]
+    fn padding_restored() {
+        let mut a = VecSimd::<f32x4>::with(1.0, 3);
+        let b = VecSimd::<f32x4>::with(0.0, 3);
+
+        a.set_padding(Padding::Value(f32::NEG_INFINITY));
+        a /= &b;
+        a = &a * 2.0;
+
+        assert!(a.flat().iter().all(|x| x.is_infinite() && x.is_sign_positive()));
+        assert!(a[0].to_array()[3].is_infinite() && a[0].to_array()[3].is_sign_negative());
+    }
+
+    #[test]
+    #[should_panic(expected = "VecSimd length mismatch")]
+    fn length_mismatch() {
+        let a = VecSimd::<f32x4>::with(1.0, 3);
+        let b = VecSimd::<f32x4>::with(1.0, 4);
+
+        let _ = &a + &b;
+    }
+}
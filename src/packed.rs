@@ -1,8 +1,12 @@
-use std::ops::Range;
+use std::{alloc::Layout, ops::Range};
 
 use super::{
-    conversion::{simd_container_flat_slice, simd_container_flat_slice_mut},
+    conversion::{packed_as_flat, packed_as_flat_mut, simd_container_flat_slice, simd_container_flat_slice_mut},
+    gather::gather,
+    padding::Padding,
     traits::Simd,
+    transpose::transpose_tile,
+    AccessStrategy, Error, Matrix2DIter, Rows,
 };
 
 #[derive(Clone, Debug)]
@@ -14,27 +18,322 @@ where
     pub(crate) row_length: usize,
     pub(crate) vectors_per_row: usize,
     pub(crate) data: Vec<T>,
+    pub(crate) padding: Padding<T::Element>,
 }
 
 impl<T> PackedMxN<T>
 where
     T: Simd + Default + Clone,
 {
+    /// Allocates `rows` rows of `row_length` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size overflows or the allocation fails.
     #[inline]
     pub(crate) fn with(default: T, rows: usize, row_length: usize) -> Self {
-        let vectors_per_row = match (row_length / T::LANES, row_length % T::LANES) {
+        Self::try_with(default, rows, row_length).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Allocates `rows` rows of `row_length` elements, reporting overflows and allocation failures.
+    #[inline]
+    pub(crate) fn try_with(default: T, rows: usize, row_length: usize) -> Result<Self, Error> {
+        let mut rval = Self::try_with_layout(default, rows, row_length, Self::vectors_for_length(row_length), rows)?;
+
+        rval.reset_padding();
+        Ok(rval)
+    }
+
+    /// Allocates `storage_rows` rows of `vectors_per_row` vectors, holding `rows` rows of `row_length`
+    /// elements, without applying the padding policy.
+    #[inline]
+    pub(crate) fn try_with_layout(default: T, rows: usize, row_length: usize, vectors_per_row: usize, storage_rows: usize) -> Result<Self, Error> {
+        debug_assert!(rows <= storage_rows && row_length <= vectors_per_row.saturating_mul(T::LANES));
+
+        let vectors = vectors_per_row.checked_mul(storage_rows).ok_or(Error::CapacityOverflow)?;
+        let layout = Layout::array::<T>(vectors).map_err(|_| Error::CapacityOverflow)?;
+
+        let mut data = Vec::new();
+        data.try_reserve_exact(vectors).map_err(|_| Error::AllocationFailed { bytes: layout.size() })?;
+        data.resize(vectors, default);
+
+        Ok(Self {
+            rows,
+            row_length,
+            vectors_per_row,
+            data,
+            padding: Padding::default(),
+        })
+    }
+
+    /// Number of SIMD vectors needed to hold `row_length` elements.
+    #[inline]
+    pub(crate) const fn vectors_for_length(row_length: usize) -> usize {
+        match (row_length / T::LANES, row_length % T::LANES) {
             (x, 0) => x,
             (x, _) => x + 1,
+        }
+    }
+
+    /// Changes the length of a single-row container, adding or dropping trailing vectors as
+    /// needed, and restores the padding policy.
+    #[inline]
+    pub(crate) fn set_single_row_length(&mut self, row_length: usize) {
+        debug_assert_eq!(self.rows, 1);
+
+        self.vectors_per_row = Self::vectors_for_length(row_length);
+        self.row_length = row_length;
+        self.data.resize(self.vectors_per_row, T::default());
+        self.reset_padding();
+    }
+
+    /// Changes the padding policy and applies it.
+    #[inline]
+    pub(crate) fn set_padding(&mut self, padding: Padding<T::Element>) {
+        self.padding = padding;
+        self.reset_padding();
+    }
+
+    /// Sets all lanes past `row_length` in the last vector of each row according to the padding policy.
+    #[inline]
+    pub(crate) fn reset_padding(&mut self) {
+        self.reset_padding_for_rows(0..self.rows);
+    }
+
+    /// Applies the padding policy to the given rows only.
+    #[inline]
+    pub(crate) fn reset_padding_for_rows(&mut self, rows: Range<usize>) {
+        let value = match self.padding {
+            Padding::Zero => T::Element::default(),
+            Padding::Value(x) => x,
+            Padding::Unspecified => return,
+        };
+
+        let used = self.vectors_per_row * T::LANES;
+
+        if used == self.row_length {
+            return;
+        }
+
+        for row in rows {
+            let range = self.range_for_row(row);
+            let flat = simd_container_flat_slice_mut(&mut self.data[range], used);
+
+            flat[self.row_length..].fill(value);
+        }
+    }
+
+    /// Applies the padding policy to all lanes outside the matrix, with vectors placed by `A`.
+    ///
+    /// Unlike [`reset_padding`](Self::reset_padding), this also covers whole padding vectors and
+    /// padding rows, as used by [`Tiled`](crate::Tiled).
+    pub(crate) fn reset_padding_for<A: AccessStrategy>(&mut self) {
+        let value = match self.padding {
+            Padding::Zero => T::Element::default(),
+            Padding::Value(x) => x,
+            Padding::Unspecified => return,
         };
 
+        if self.vectors_per_row == 0 {
+            return;
+        }
+
+        let storage_rows = self.data.len() / self.vectors_per_row;
+
+        for row in 0..storage_rows {
+            let first = if row < self.rows { self.row_length / T::LANES } else { 0 };
+
+            for vector in first..self.vectors_per_row {
+                let start = if row < self.rows { self.row_length.saturating_sub(vector * T::LANES) } else { 0 };
+                let index = A::vector_index(row, vector, self.vectors_per_row);
+
+                self.data[index].as_array_mut()[start..].fill(value);
+            }
+        }
+    }
+
+    /// Returns an error unless `length` matches the row length.
+    #[inline]
+    pub(crate) const fn check_row_length(&self, length: usize) -> Result<(), Error> {
+        if length == self.row_length {
+            Ok(())
+        } else {
+            Err(Error::DimensionMismatch {
+                expected: self.row_length,
+                actual: length,
+            })
+        }
+    }
+
+    /// Returns an error unless `length` matches the number of rows.
+    #[inline]
+    pub(crate) const fn check_rows(&self, length: usize) -> Result<(), Error> {
+        if length == self.rows {
+            Ok(())
+        } else {
+            Err(Error::DimensionMismatch {
+                expected: self.rows,
+                actual: length,
+            })
+        }
+    }
+
+    /// Inserts a row with the given flat content at `index`, shifting later rows back.
+    ///
+    /// The length of `row` must match the row length.
+    #[inline]
+    pub(crate) fn insert_row(&mut self, index: usize, row: &[T::Element]) {
+        assert!(index <= self.rows, "insertion index {index} is out of bounds for {} rows", self.rows);
+        debug_assert_eq!(row.len(), self.row_length);
+
+        let start = self.row_start_offset(index);
+        let vectors = std::iter::repeat_n(T::default(), self.vectors_per_row);
+
+        self.data.splice(start..start, vectors);
+        self.rows += 1;
+        self.row_as_flat_mut(index).copy_from_slice(row);
+        self.reset_padding_for_rows(index..index + 1);
+    }
+
+    /// Removes the row at `index`, shifting later rows forward, and returns it as a single row.
+    #[inline]
+    pub(crate) fn remove_row(&mut self, index: usize) -> Self {
+        assert!(index < self.rows, "removal index {index} is out of bounds for {} rows", self.rows);
+
+        let range = self.range_for_row(index);
+        self.rows -= 1;
+
         Self {
-            rows,
-            row_length,
-            vectors_per_row,
-            data: vec![default; vectors_per_row * rows],
+            rows: 1,
+            row_length: self.row_length,
+            vectors_per_row: self.vectors_per_row,
+            data: self.data.drain(range).collect(),
+            padding: self.padding,
         }
     }
 
+    /// Swaps the rows `a` and `b`.
+    #[inline]
+    pub(crate) fn swap_rows(&mut self, a: usize, b: usize) {
+        assert!(a < self.rows && b < self.rows, "swap indices ({a}, {b}) are out of bounds for {} rows", self.rows);
+
+        let (low, high) = (a.min(b), a.max(b));
+
+        if low == high {
+            return;
+        }
+
+        let range = self.range_for_row(low);
+        let middle = self.row_start_offset(high);
+        let (head, tail) = self.data.split_at_mut(middle);
+
+        head[range].swap_with_slice(&mut tail[..self.vectors_per_row]);
+    }
+
+    /// Shortens the container to `rows` rows. Has no effect if it has fewer rows already.
+    #[inline]
+    pub(crate) fn truncate_rows(&mut self, rows: usize) {
+        if rows < self.rows {
+            self.rows = rows;
+            self.data.truncate(rows * self.vectors_per_row);
+        }
+    }
+
+    /// Reserves capacity for at least `additional` more rows.
+    #[inline]
+    pub(crate) fn reserve_rows(&mut self, additional: usize) {
+        self.data.reserve(additional * self.vectors_per_row);
+    }
+
+    /// Copies element `index` of every row into `target`, which must have `rows` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than `row_length`.
+    pub(crate) fn gather(&self, index: usize, target: &mut [T::Element]) {
+        assert!(index < self.row_length, "index {index} out of bounds for rows of length {}", self.row_length);
+        debug_assert_eq!(target.len(), self.rows);
+
+        gather(packed_as_flat(&self.data), index, self.vectors_per_row * T::LANES, target);
+    }
+
+    /// Copies `source`, which must have `rows` elements, into element `index` of every row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than `row_length`.
+    pub(crate) fn scatter(&mut self, index: usize, source: &[T::Element]) {
+        assert!(index < self.row_length, "index {index} out of bounds for rows of length {}", self.row_length);
+        debug_assert_eq!(source.len(), self.rows);
+
+        let stride = self.vectors_per_row * T::LANES;
+        let target = packed_as_flat_mut(&mut self.data);
+
+        for (x, value) in target[index..].iter_mut().step_by(stride).zip(source) {
+            *x = *value;
+        }
+    }
+
+    /// Returns a copy where rows become columns and vice versa.
+    pub(crate) fn transposed(&self) -> Self {
+        let mut rval = Self::with(T::default(), self.row_length, self.rows);
+
+        self.transpose_into::<Rows>(&mut rval);
+        rval.set_padding(self.padding);
+        rval
+    }
+
+    /// Writes element `(r, c)` of this container to element `(c, r)` of `target`, with vectors
+    /// placed by `A` on both sides, leaving the padding of `target` untouched.
+    ///
+    /// `target` must have `row_length` rows of `rows` elements.
+    ///
+    /// Works in tiles of `LANES x LANES` elements. Full tiles are transposed in registers where
+    /// [`transpose_tile`] supports `T`, reading `LANES` source vectors and writing `LANES` target
+    /// vectors. Tiles at the right and bottom edge, and all tiles without such support, are copied
+    /// element by element.
+    pub(crate) fn transpose_into<A: AccessStrategy>(&self, target: &mut Self) {
+        debug_assert_eq!((target.rows, target.row_length), (self.row_length, self.rows));
+
+        let lanes = T::LANES;
+        let (source_vpr, target_vpr) = (self.vectors_per_row, target.vectors_per_row);
+
+        for r_0 in (0..self.rows).step_by(lanes) {
+            let r_n = lanes.min(self.rows - r_0);
+
+            for c_0 in (0..self.row_length).step_by(lanes) {
+                let c_n = lanes.min(self.row_length - c_0);
+                let (source_vector, target_vector) = (c_0 / lanes, r_0 / lanes);
+
+                if r_n == lanes
+                    && c_n == lanes
+                    && transpose_tile(
+                        &self.data,
+                        |r| A::vector_index(r_0 + r, source_vector, source_vpr),
+                        &mut target.data,
+                        |c| A::vector_index(c_0 + c, target_vector, target_vpr),
+                    )
+                {
+                    continue;
+                }
+
+                for r in r_0..r_0 + r_n {
+                    let source_row = self.data[A::vector_index(r, source_vector, source_vpr)].as_array();
+
+                    for c in c_0..c_0 + c_n {
+                        target.data[A::vector_index(c, target_vector, target_vpr)].as_array_mut()[r - r_0] = source_row[c - c_0];
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns an iterator over the SIMD vectors of each row.
+    #[inline]
+    pub(crate) fn row_chunks(&self) -> Matrix2DIter<'_, T, Rows> {
+        Matrix2DIter::new(&self.data, self.rows, self.vectors_per_row, self.row_length)
+    }
+
     /// Computes an offset for a vector and attribute.
     #[inline]
     pub(crate) const fn row_start_offset(&self, row: usize) -> usize {
@@ -66,7 +365,11 @@ where
 #[cfg(test)]
 mod test {
     use super::PackedMxN;
-    use crate::arch::f32x4;
+    use crate::{
+        arch::{f32x4, f32x8, f64x2, i32x4, u8x16},
+        traits::Simd,
+        Error, Padding,
+    };
 
     #[test]
     fn allocation_size() {
@@ -77,6 +380,16 @@ mod test {
         assert_eq!(r_2.data.len(), 2);
     }
 
+    #[test]
+    fn try_with() {
+        let zero = f32x4::splat(0.0);
+
+        assert_eq!(PackedMxN::try_with(zero, 3, 5).map(|x| x.data.len()), Ok(6));
+        assert_eq!(PackedMxN::try_with(zero, 1 << 40, 1 << 40).err(), Some(Error::CapacityOverflow));
+        assert_eq!(PackedMxN::try_with(zero, 1 << 60, 4).err(), Some(Error::CapacityOverflow));
+        assert_eq!(PackedMxN::try_with(zero, 1 << 58, 4).err(), Some(Error::AllocationFailed { bytes: 1 << 62 }));
+    }
+
     #[test]
     fn start_offset() {
         let r = PackedMxN::<f32x4>::with(f32x4::splat(0.0), 16, 16);
@@ -92,6 +405,101 @@ mod test {
         assert_eq!(r.range_for_row(2), 8..12);
     }
 
+    #[test]
+    fn padding() {
+        let mut r = PackedMxN::<i32x4>::with(i32x4::splat(1), 2, 3);
+
+        assert_eq!(r.data[0].to_array(), [1, 1, 1, 0]);
+        assert_eq!(r.data[1].to_array(), [1, 1, 1, 0]);
+
+        r.set_padding(Padding::Value(9));
+
+        assert_eq!(r.data[0].to_array(), [1, 1, 1, 9]);
+        assert_eq!(r.data[1].to_array(), [1, 1, 1, 9]);
+
+        r.data[0] = i32x4::splat(2);
+        r.set_padding(Padding::Unspecified);
+
+        assert_eq!(r.data[0].to_array(), [2, 2, 2, 2]);
+    }
+
+    #[test]
+    fn transposed() {
+        let mut r = PackedMxN::<i32x4>::with(i32x4::splat(0), 5, 6);
+
+        for row in 0..5 {
+            for (x, value) in r.row_as_flat_mut(row).iter_mut().enumerate() {
+                *value = i32::try_from(row * 10 + x).unwrap();
+            }
+        }
+
+        let t = r.transposed();
+
+        assert_eq!((t.rows, t.row_length, t.vectors_per_row), (6, 5, 2));
+        assert_eq!(t.row_as_flat(0), &[0, 10, 20, 30, 40]);
+        assert_eq!(t.row_as_flat(5), &[5, 15, 25, 35, 45]);
+        assert_eq!(t.data[1].to_array(), [40, 0, 0, 0]);
+    }
+
+    #[test]
+    fn transposed_tiles() {
+        fn check<T: Simd>(rows: usize, row_length: usize, value: impl Fn(usize, usize) -> T::Element)
+        where
+            T::Element: PartialEq,
+        {
+            let mut r = PackedMxN::<T>::with(T::default(), rows, row_length);
+
+            for row in 0..rows {
+                for (x, element) in r.row_as_flat_mut(row).iter_mut().enumerate() {
+                    *element = value(row, x);
+                }
+            }
+
+            let t = r.transposed();
+
+            for row in 0..rows {
+                for x in 0..row_length {
+                    assert!(t.row_as_flat(x)[row] == value(row, x));
+                }
+            }
+        }
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+        {
+            check::<f32x8>(19, 21, |r, c| (r * 100 + c) as f32);
+            check::<f64x2>(7, 4, |r, c| (r * 100 + c) as f64);
+            check::<u8x16>(33, 40, |r, c| (r * 7 + c) as u8);
+        }
+    }
+
+    #[test]
+    fn insert_remove_swap() {
+        let mut r = PackedMxN::<i32x4>::with(i32x4::splat(0), 0, 5);
+
+        r.insert_row(0, &[1, 1, 1, 1, 1]);
+        r.insert_row(1, &[3, 3, 3, 3, 3]);
+        r.insert_row(1, &[2, 2, 2, 2, 2]);
+
+        assert_eq!((r.rows, r.data.len()), (3, 6));
+        assert_eq!(r.row_as_flat(1), &[2, 2, 2, 2, 2]);
+
+        r.swap_rows(2, 0);
+
+        assert_eq!(r.row_as_flat(0), &[3, 3, 3, 3, 3]);
+        assert_eq!(r.row_as_flat(2), &[1, 1, 1, 1, 1]);
+
+        let removed = r.remove_row(0);
+
+        assert_eq!((removed.rows, removed.data.len()), (1, 2));
+        assert_eq!(removed.row_as_flat(0), &[3, 3, 3, 3, 3]);
+        assert_eq!(r.row_as_flat(0), &[2, 2, 2, 2, 2]);
+        assert_eq!(r.data[1].to_array(), [2, 0, 0, 0]);
+
+        r.truncate_rows(1);
+
+        assert_eq!((r.rows, r.data.len()), (1, 2));
+    }
+
     #[test]
     fn slice() {
         let r = PackedMxN::<f32x4>::with(f32x4::splat(0.0), 16, 16);
@@ -0,0 +1,36 @@
+/// Defines what the unused lanes in the last SIMD vector of a row contain.
+///
+/// If the flat length of a [`VecSimd`](crate::VecSimd) or the row length of a
+/// [`MatSimd`](crate::MatSimd) is not a multiple of the SIMD width, the last vector of each
+/// row has lanes that are not part of the flat view, but still take part in SIMD operations on
+/// the packed vectors. This policy pins down their content.
+///
+/// All constructors and all mutations through the flat view or this crate's methods maintain
+/// the policy. Writes to whole SIMD vectors (e.g., through `DerefMut` or `row_mut`) may
+/// overwrite padding lanes; call `reset_padding()` afterwards to restore them.
+///
+/// # Example
+///
+/// ```rust
+/// use simd_aligned::{VecSimd, Padding, arch::f32x4};
+///
+/// let mut v = VecSimd::<f32x4>::with(1.0, 3);
+///
+/// // Padding lanes are `0.0` by default.
+/// assert_eq!(v[0].as_array_ref(), &[1.0, 1.0, 1.0, 0.0]);
+///
+/// // Use `-inf` instead, so SIMD `max` kernels can ignore the tail.
+/// v.set_padding(Padding::Value(f32::NEG_INFINITY));
+///
+/// assert_eq!(v[0].as_array_ref(), &[1.0, 1.0, 1.0, f32::NEG_INFINITY]);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Padding<E> {
+    /// Padding lanes hold `E::default()`, i.e., `0` for all numeric types.
+    #[default]
+    Zero,
+    /// Padding lanes hold the given value.
+    Value(E),
+    /// Padding lanes are left alone and may hold any value. This is the cheapest policy.
+    Unspecified,
+}
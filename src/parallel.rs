@@ -0,0 +1,155 @@
+//! Parallel iteration with [rayon](https://docs.rs/rayon), available with the `rayon` feature.
+//!
+//! All iterators yield aligned `&[T]` (or `&mut [T]`) slices of SIMD vectors, just like their
+//! sequential counterparts. Element-wise operators and reductions of this crate switch to
+//! parallel execution on their own for large inputs when the feature is enabled.
+use rayon::{
+    iter::{Either, IndexedParallelIterator, IntoParallelIterator, ParallelIterator},
+    slice::{ParallelSlice, ParallelSliceMut},
+};
+
+use crate::{packed::PackedMxN, traits::Simd, Columns, MatSimd, Rows, VecSimd};
+
+/// Returns a parallel iterator over the SIMD vectors of all rows of `packed`.
+///
+/// Yields one empty slice per row if rows have length 0, like the sequential iterators.
+fn par_rows<T>(packed: &PackedMxN<T>) -> impl IndexedParallelIterator<Item = &[T]>
+where
+    T: Simd + Send + Sync,
+{
+    match packed.vectors_per_row {
+        0 => Either::Left((0..packed.rows).into_par_iter().map(|_| <&[T]>::default())),
+        n => Either::Right(packed.data.par_chunks_exact(n)),
+    }
+}
+
+/// Returns a parallel iterator over the SIMD vectors of all rows of `packed`, allowing modification.
+fn par_rows_mut<T>(packed: &mut PackedMxN<T>) -> impl IndexedParallelIterator<Item = &mut [T]>
+where
+    T: Simd + Send + Sync,
+{
+    match packed.vectors_per_row {
+        0 => Either::Left((0..packed.rows).into_par_iter().map(|_| <&mut [T]>::default())),
+        n => Either::Right(packed.data.par_chunks_exact_mut(n)),
+    }
+}
+
+impl<T> MatSimd<T, Rows>
+where
+    T: Simd + Default + Clone + Send + Sync,
+{
+    /// Returns a parallel iterator over the SIMD vectors of all rows.
+    #[inline]
+    #[must_use]
+    pub fn par_row_iter(&self) -> impl IndexedParallelIterator<Item = &[T]> {
+        par_rows(&self.simd_rows)
+    }
+
+    /// Returns a parallel iterator over the SIMD vectors of all rows, allowing modification.
+    #[inline]
+    pub fn par_row_iter_mut(&mut self) -> impl IndexedParallelIterator<Item = &mut [T]> {
+        par_rows_mut(&mut self.simd_rows)
+    }
+}
+
+impl<T> MatSimd<T, Columns>
+where
+    T: Simd + Default + Clone + Send + Sync,
+{
+    /// Returns a parallel iterator over the SIMD vectors of all columns.
+    #[inline]
+    #[must_use]
+    pub fn par_column_iter(&self) -> impl IndexedParallelIterator<Item = &[T]> {
+        par_rows(&self.simd_rows)
+    }
+
+    /// Returns a parallel iterator over the SIMD vectors of all columns, allowing modification.
+    #[inline]
+    pub fn par_column_iter_mut(&mut self) -> impl IndexedParallelIterator<Item = &mut [T]> {
+        par_rows_mut(&mut self.simd_rows)
+    }
+}
+
+impl<T> VecSimd<T>
+where
+    T: Simd + Default + Clone + Send + Sync,
+{
+    /// Returns a parallel iterator over chunks of `chunk_size` SIMD vectors. The last chunk
+    /// might be shorter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    #[inline]
+    #[must_use]
+    pub fn par_chunks(&self, chunk_size: usize) -> rayon::slice::Chunks<'_, T> {
+        self.simd_rows.data.par_chunks(chunk_size)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rayon::prelude::*;
+
+    use crate::{arch::i32x4, blas1, Columns, MatSimd, Rows, VecSimd};
+
+    #[test]
+    fn row_column_iter() {
+        let mut m = MatSimd::<i32x4, Rows>::with_dimension(100, 6);
+        let mut c = MatSimd::<i32x4, Columns>::with_dimension(6, 100);
+
+        m.par_row_iter_mut().enumerate().for_each(|(i, row)| row.fill(i32x4::splat(i32::try_from(i).unwrap())));
+        c.par_column_iter_mut().for_each(|column| column[0] = i32x4::splat(1));
+
+        assert_eq!(m.par_row_iter().count(), 100);
+        assert_eq!(m.row_as_flat(99), &[99; 6]);
+        assert_eq!(m.row(99)[1].to_array(), [99, 99, 99, 99]);
+        assert_eq!(c.par_column_iter().map(|column| column[0].to_array()[0]).sum::<i32>(), 100);
+    }
+
+    #[test]
+    fn empty_rows() {
+        let mut m = MatSimd::<i32x4, Rows>::with_dimension(3, 0);
+        let mut c = MatSimd::<i32x4, Columns>::with_dimension(0, 3);
+
+        assert_eq!(m.par_row_iter().len(), 3);
+        assert_eq!(m.par_row_iter_mut().map(|row| row.len()).sum::<usize>(), 0);
+        assert_eq!(c.par_column_iter().count(), 3);
+        assert_eq!(c.par_column_iter_mut().len(), 3);
+        assert_eq!(MatSimd::<i32x4, Rows>::with_dimension(0, 3).par_row_iter().len(), 0);
+    }
+
+    #[test]
+    fn chunks() {
+        let v = VecSimd::<i32x4>::with(1, 42);
+
+        assert_eq!(v.par_chunks(4).count(), 3);
+        assert_eq!(v.par_chunks(4).map(<[i32x4]>::len).sum::<usize>(), 11);
+    }
+
+    #[test]
+    fn matches_sequential() {
+        let flat = (0..1_000_000_u32).map(|i| f64::from(i % 1013) / 7.0 - 50.0).collect::<Vec<_>>();
+        let x = VecSimd::<crate::arch::f64x4>::from_slice(&flat);
+
+        // Same order as the sequential kernel: lane-wise sums over blocks of 128 vectors, then over lanes.
+        let mut lanes = [0.0; 4];
+
+        for block in flat.chunks(128 * 4) {
+            let mut partial = [0.0; 4];
+
+            for (i, a) in block.iter().enumerate() {
+                partial[i % 4] += a * a;
+            }
+
+            for (lane, p) in lanes.iter_mut().zip(partial) {
+                *lane += p;
+            }
+        }
+
+        let expected = lanes.iter().fold(0.0, |a, b| a + b);
+
+        assert_eq!(blas1::dot(&x, &x).to_bits(), expected.to_bits());
+        assert_eq!((&x + &x).flat()[999_999].to_bits(), (2.0 * flat[999_999]).to_bits());
+    }
+}
@@ -0,0 +1,376 @@
+use std::{cmp::Ordering, ops::Mul};
+
+use crate::{
+    dispatch, kernels,
+    packed::PackedMxN,
+    traits::{Float, MaybeSendSync, Simd, SimdFloat},
+    Columns, MatSimd, Rows, VecSimd,
+};
+
+/// Returns `true` if `a` and `b` compare equal, or are both `NaN`.
+#[inline]
+fn same<E: PartialOrd>(a: &E, b: &E) -> bool {
+    let is_nan = |x: &E| x.partial_cmp(x).is_none();
+    a.partial_cmp(b).map_or_else(|| is_nan(a) && is_nan(b), Ordering::is_eq)
+}
+
+/// Reduces each row of `packed` with `op`, or returns `None` if the rows are empty.
+#[inline]
+fn each_row<T>(packed: &PackedMxN<T>, op: impl Fn(T, T) -> T + MaybeSendSync) -> Option<VecSimd<T>>
+where
+    T: Simd + MaybeSendSync,
+    T::Element: MaybeSendSync,
+{
+    if packed.row_length == 0 && packed.rows > 0 {
+        return None;
+    }
+
+    let level = dispatch::level();
+    let values = kernels::map_chunks(&packed.data, packed.vectors_per_row.max(1), |row| kernels::reduce(level, row, packed.row_length, &op));
+
+    values.into_iter().collect()
+}
+
+/// Reduces all rows of `packed` lane-wise with `op` in ascending order, or returns `None` if there are no rows.
+#[inline]
+fn across_rows<T>(packed: &PackedMxN<T>, op: impl Fn(T, T) -> T + MaybeSendSync) -> Option<VecSimd<T>>
+where
+    T: Simd + MaybeSendSync,
+{
+    if packed.rows == 0 {
+        return None;
+    }
+
+    let mut rval = VecSimd::with_default(packed.row_length);
+    rval.simd_rows.data.copy_from_slice(&packed.data[packed.range_for_row(0)]);
+
+    let level = dispatch::level();
+
+    for row in packed.row_chunks().skip(1) {
+        kernels::zip_map(level, &mut rval.simd_rows.data, row, &op);
+    }
+
+    rval.simd_rows.reset_padding();
+    Some(rval)
+}
+
+/// Divides all elements of `x` by `n`, or returns `None` if `n` is 0.
+#[inline]
+fn divide<T>(mut x: VecSimd<T>, n: usize) -> Option<VecSimd<T>>
+where
+    T: SimdFloat + MaybeSendSync,
+{
+    if n == 0 {
+        return None;
+    }
+
+    let n = T::splat(T::Element::from_usize(n));
+
+    kernels::map(dispatch::level(), &mut x.simd_rows.data, |a| a / n);
+    x.simd_rows.reset_padding();
+    Some(x)
+}
+
+impl<T> VecSimd<T>
+where
+    T: Simd + MaybeSendSync,
+{
+    /// Returns the sum of all elements, or `0` if the vector is empty.
+    ///
+    /// Padding lanes are ignored. Integer sums wrap on overflow.
+    ///
+    /// # Accumulation Order
+    ///
+    /// All reductions of [`VecSimd`] and [`MatSimd`] combine elements in the same, fixed order:
+    /// the SIMD vectors are combined lane-wise in blocks, the blocks are combined lane-wise in
+    /// ascending order, and the used lanes of the partial last vector are combined with the
+    /// matching lanes of the result. Finally, the lanes are combined in ascending order. This
+    /// order is the same with and without the `rayon` feature, so floating point results are
+    /// reproducible, but may differ slightly from a sequential scalar loop.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use simd_aligned::{VecSimd, arch::i32x4};
+    ///
+    /// let v = VecSimd::<i32x4>::from_slice(&[1, 2, 3, 4, 5]);
+    ///
+    /// assert_eq!(v.sum(), 15);
+    /// assert_eq!(v.max(), Some(5));
+    /// assert_eq!(v.argmin(), Some(0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sum(&self) -> T::Element {
+        kernels::reduce(dispatch::level(), &self.simd_rows.data, self.simd_rows.row_length, |a, b| a + b).unwrap_or_default()
+    }
+
+    /// Returns the smallest element, or `None` if the vector is empty.
+    ///
+    /// `NaN` elements are ignored unless all elements are `NaN`.
+    #[inline]
+    #[must_use]
+    pub fn min(&self) -> Option<T::Element> {
+        kernels::reduce(dispatch::level(), &self.simd_rows.data, self.simd_rows.row_length, T::min)
+    }
+
+    /// Returns the largest element, or `None` if the vector is empty.
+    ///
+    /// `NaN` elements are ignored unless all elements are `NaN`.
+    #[inline]
+    #[must_use]
+    pub fn max(&self) -> Option<T::Element> {
+        kernels::reduce(dispatch::level(), &self.simd_rows.data, self.simd_rows.row_length, T::max)
+    }
+
+    /// Returns the index of the first smallest element (see [`min`](Self::min)), or `None` if the vector is empty.
+    #[inline]
+    #[must_use]
+    pub fn argmin(&self) -> Option<usize> {
+        let min = self.min()?;
+        self.flat().iter().position(|x| same(x, &min))
+    }
+
+    /// Returns the index of the first largest element (see [`max`](Self::max)), or `None` if the vector is empty.
+    #[inline]
+    #[must_use]
+    pub fn argmax(&self) -> Option<usize> {
+        let max = self.max()?;
+        self.flat().iter().position(|x| same(x, &max))
+    }
+}
+
+impl<T> VecSimd<T>
+where
+    T: Simd + Mul<Output = T> + MaybeSendSync,
+{
+    /// Returns the product of all elements, or `None` if the vector is empty.
+    ///
+    /// Padding lanes are ignored. Integer products wrap on overflow.
+    #[inline]
+    #[must_use]
+    pub fn product(&self) -> Option<T::Element> {
+        kernels::reduce(dispatch::level(), &self.simd_rows.data, self.simd_rows.row_length, |a, b| a * b)
+    }
+}
+
+impl<T> VecSimd<T>
+where
+    T: SimdFloat + MaybeSendSync,
+{
+    /// Returns the arithmetic mean of all elements, or `None` if the vector is empty.
+    #[inline]
+    #[must_use]
+    pub fn mean(&self) -> Option<T::Element> {
+        let length = self.simd_rows.row_length;
+        (length > 0).then(|| self.sum() / T::Element::from_usize(length))
+    }
+}
+
+macro_rules! impl_mat_reductions {
+    ($strategy:ty, $packed:literal, $across:literal, [$sums:ident, $minima:ident, $maxima:ident, $products:ident, $means:ident], [$sums_across:ident, $minima_across:ident, $maxima_across:ident, $products_across:ident, $means_across:ident]) => {
+        impl<T> MatSimd<T, $strategy>
+        where
+            T: Simd + MaybeSendSync,
+            T::Element: MaybeSendSync,
+        {
+            #[doc = concat!("Returns the sum of each ", $packed, ".")]
+            ///
+            /// See [`VecSimd::sum`] for the accumulation order.
+            #[inline]
+            #[must_use]
+            pub fn $sums(&self) -> VecSimd<T> {
+                each_row(&self.simd_rows, |a, b| a + b).unwrap_or_else(|| VecSimd::with_default(self.simd_rows.rows))
+            }
+
+            #[doc = concat!("Returns the sum of each ", $across, ".")]
+            ///
+            #[doc = concat!("Adds the ", $packed, "s lane-wise in ascending order.")]
+            #[inline]
+            #[must_use]
+            pub fn $sums_across(&self) -> VecSimd<T> {
+                across_rows(&self.simd_rows, |a, b| a + b).unwrap_or_else(|| VecSimd::with_default(self.simd_rows.row_length))
+            }
+
+            #[doc = concat!("Returns the smallest element of each ", $packed, ", or `None` if the ", $packed, "s are empty.")]
+            #[inline]
+            #[must_use]
+            pub fn $minima(&self) -> Option<VecSimd<T>> {
+                each_row(&self.simd_rows, T::min)
+            }
+
+            #[doc = concat!("Returns the smallest element of each ", $across, ", or `None` if the ", $across, "s are empty.")]
+            #[inline]
+            #[must_use]
+            pub fn $minima_across(&self) -> Option<VecSimd<T>> {
+                across_rows(&self.simd_rows, T::min)
+            }
+
+            #[doc = concat!("Returns the largest element of each ", $packed, ", or `None` if the ", $packed, "s are empty.")]
+            #[inline]
+            #[must_use]
+            pub fn $maxima(&self) -> Option<VecSimd<T>> {
+                each_row(&self.simd_rows, T::max)
+            }
+
+            #[doc = concat!("Returns the largest element of each ", $across, ", or `None` if the ", $across, "s are empty.")]
+            #[inline]
+            #[must_use]
+            pub fn $maxima_across(&self) -> Option<VecSimd<T>> {
+                across_rows(&self.simd_rows, T::max)
+            }
+        }
+
+        impl<T> MatSimd<T, $strategy>
+        where
+            T: Simd + Mul<Output = T> + MaybeSendSync,
+            T::Element: MaybeSendSync,
+        {
+            #[doc = concat!("Returns the product of each ", $packed, ", or `None` if the ", $packed, "s are empty.")]
+            #[inline]
+            #[must_use]
+            pub fn $products(&self) -> Option<VecSimd<T>> {
+                each_row(&self.simd_rows, |a, b| a * b)
+            }
+
+            #[doc = concat!("Returns the product of each ", $across, ", or `None` if the ", $across, "s are empty.")]
+            #[inline]
+            #[must_use]
+            pub fn $products_across(&self) -> Option<VecSimd<T>> {
+                across_rows(&self.simd_rows, |a, b| a * b)
+            }
+        }
+
+        impl<T> MatSimd<T, $strategy>
+        where
+            T: SimdFloat + MaybeSendSync,
+            T::Element: MaybeSendSync,
+        {
+            #[doc = concat!("Returns the arithmetic mean of each ", $packed, ", or `None` if the ", $packed, "s are empty.")]
+            #[inline]
+            #[must_use]
+            pub fn $means(&self) -> Option<VecSimd<T>> {
+                divide(self.$sums(), self.simd_rows.row_length)
+            }
+
+            #[doc = concat!("Returns the arithmetic mean of each ", $across, ", or `None` if the ", $across, "s are empty.")]
+            #[inline]
+            #[must_use]
+            pub fn $means_across(&self) -> Option<VecSimd<T>> {
+                divide(self.$sums_across(), self.simd_rows.rows)
+            }
+        }
+    };
+}
+
+impl_mat_reductions!(
+    Rows,
+    "row",
+    "column",
+    [row_sums, row_minima, row_maxima, row_products, row_means],
+    [column_sums, column_minima, column_maxima, column_products, column_means]
+);
+
+impl_mat_reductions!(
+    Columns,
+    "column",
+    "row",
+    [column_sums, column_minima, column_maxima, column_products, column_means],
+    [row_sums, row_minima, row_maxima, row_products, row_means]
+);
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        arch::{f64x4, i32x4, u8x16},
+        Columns, MatSimd, Padding, Rows, VecSimd,
+    };
+
+    #[test]
+    fn vec_reductions() {
+        let mut v = VecSimd::<i32x4>::from_slice(&[3, -1, 4, 1, -5, 9, 2]);
+
+        v.set_padding(Padding::Value(-100));
+
+        assert_eq!(v.sum(), 13);
+        assert_eq!(v.product(), Some(1080));
+        assert_eq!((v.min(), v.max()), (Some(-5), Some(9)));
+        assert_eq!((v.argmin(), v.argmax()), (Some(4), Some(5)));
+
+        let empty = VecSimd::<i32x4>::with(1, 0);
+
+        assert_eq!(empty.sum(), 0);
+        assert_eq!((empty.product(), empty.min(), empty.argmax()), (None, None, None));
+    }
+
+    #[test]
+    fn vec_wrapping() {
+        let v = VecSimd::<u8x16>::with(200, 33);
+
+        assert_eq!(v.sum(), 200_u8.wrapping_mul(33));
+        assert_eq!(v.max(), Some(200));
+    }
+
+    #[test]
+    fn vec_float() {
+        let flat = (0..1001_u16).map(|i| f64::from(i % 37) - 10.5).collect::<Vec<_>>();
+        let mut v = VecSimd::<f64x4>::from_slice(&flat);
+
+        v.set_padding(Padding::Value(f64::NAN));
+
+        let sum = flat.iter().sum::<f64>();
+        let mean = v.mean().unwrap();
+
+        assert!((v.sum() - sum).abs() < 1e-9);
+        assert!((mean - sum / 1001.0).abs() < 1e-12);
+        assert_eq!(v.argmax(), Some(36));
+
+        v.flat_mut()[3] = f64::NAN;
+
+        assert_eq!(v.min().map(f64::to_bits), Some((-10.5_f64).to_bits()));
+        assert_eq!(VecSimd::<f64x4>::with(f64::NAN, 3).argmin(), Some(0));
+        assert_eq!(VecSimd::<f64x4>::with(0.0, 0).mean(), None);
+    }
+
+    #[test]
+    fn mat_reductions() {
+        let mut rows = MatSimd::<i32x4, Rows>::with_dimension(3, 5);
+        let mut columns = MatSimd::<i32x4, Columns>::with_dimension(3, 5);
+
+        for (i, row) in [[1, 2, 3, 4, 5], [-1, 0, 7, 1, 1], [2, 2, 2, 2, 2]].iter().enumerate() {
+            rows.row_as_flat_mut(i).copy_from_slice(row);
+
+            for (j, x) in row.iter().enumerate() {
+                columns.flat_mut()[(i, j)] = *x;
+            }
+        }
+
+        rows.set_padding(Padding::Value(99));
+
+        for (sums, sums_across) in [(rows.row_sums(), rows.column_sums()), (columns.row_sums(), columns.column_sums())] {
+            assert_eq!(sums.flat(), &[15, 8, 10]);
+            assert_eq!(sums_across.flat(), &[2, 4, 12, 7, 8]);
+        }
+
+        assert_eq!(rows.row_minima().unwrap().flat(), &[1, -1, 2]);
+        assert_eq!(rows.column_maxima().unwrap().flat(), &[2, 2, 7, 4, 5]);
+        assert_eq!(columns.column_products().unwrap().flat(), &[-2, 0, 42, 8, 10]);
+        assert_eq!(columns.row_products().unwrap().flat(), &[120, 0, 32]);
+        assert_eq!(columns.row_maxima().unwrap()[0].to_array(), [5, 7, 2, 0]);
+
+        assert!(MatSimd::<i32x4, Rows>::with_dimension(2, 0).row_minima().is_none());
+        assert!(MatSimd::<i32x4, Rows>::with_dimension(0, 2).column_minima().is_none());
+        assert_eq!(MatSimd::<i32x4, Rows>::with_dimension(2, 0).row_sums().flat(), &[0, 0]);
+    }
+
+    #[test]
+    fn mat_means() {
+        let mut m = MatSimd::<f64x4, Rows>::with_dimension(2, 3);
+
+        m.row_as_flat_mut(0).copy_from_slice(&[1.0, 2.0, 3.0]);
+        m.row_as_flat_mut(1).copy_from_slice(&[3.0, 4.0, 8.0]);
+
+        assert_eq!(m.row_means().unwrap().flat(), &[2.0, 5.0]);
+        assert_eq!(m.column_means().unwrap().flat(), &[2.0, 3.0, 5.5]);
+    }
+}
@@ -0,0 +1,209 @@
+//! Serde support, available with the `serde` feature.
+//!
+//! Only the logical content is stored, never padding or lane widths, so data saved with one SIMD
+//! type (e.g., `f32x8`) can be loaded with another one of the same element type (e.g., `f32x4`).
+//!
+//! - [`VecSimd`] is stored as a sequence of its flat elements.
+//! - [`MatSimd`] is stored as a struct with fields `rows`, `columns` and `data`, where `data`
+//!   holds all elements in row-major order, no matter the [`AccessStrategy`] of the matrix.
+use std::{fmt, marker::PhantomData};
+
+use serde::{
+    de::{self, MapAccess, SeqAccess, Visitor},
+    ser::SerializeStruct,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{traits::Simd, AccessStrategy, MatSimd, VecSimd};
+
+const FIELDS: &[&str] = &["rows", "columns", "data"];
+
+impl<T> Serialize for VecSimd<T>
+where
+    T: Simd,
+    T::Element: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.flat())
+    }
+}
+
+impl<'de, T> Deserialize<'de> for VecSimd<T>
+where
+    T: Simd,
+    T::Element: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::<T::Element>::deserialize(deserializer).map(Self::from)
+    }
+}
+
+/// Serializes the elements of a matrix in row-major order.
+struct RowMajor<'a, T, O>(&'a MatSimd<T, O>)
+where
+    T: Simd,
+    O: AccessStrategy;
+
+impl<T, O> Serialize for RowMajor<'_, T, O>
+where
+    T: Simd,
+    T::Element: Serialize,
+    O: AccessStrategy,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (rows, columns) = self.0.dimension();
+        let flat = &self.0.flat();
+
+        serializer.collect_seq((0..rows).flat_map(|row| (0..columns).map(move |column| flat[(row, column)])))
+    }
+}
+
+impl<T, O> Serialize for MatSimd<T, O>
+where
+    T: Simd,
+    T::Element: Serialize,
+    O: AccessStrategy,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (rows, columns) = self.dimension();
+        let mut state = serializer.serialize_struct("MatSimd", 3)?;
+
+        state.serialize_field("rows", &rows)?;
+        state.serialize_field("columns", &columns)?;
+        state.serialize_field("data", &RowMajor(self))?;
+        state.end()
+    }
+}
+
+/// Builds a matrix from row-major `data`, which must hold exactly `rows * columns` elements.
+fn from_row_major<T, O, E>(rows: usize, columns: usize, data: &[T::Element]) -> Result<MatSimd<T, O>, E>
+where
+    T: Simd,
+    O: AccessStrategy,
+    E: de::Error,
+{
+    let expected = rows.checked_mul(columns).ok_or_else(|| E::custom(format_args!("dimension {rows} x {columns} overflows")))?;
+
+    if data.len() != expected {
+        return Err(E::invalid_length(data.len(), &format!("{expected} elements for a {rows} x {columns} matrix").as_str()));
+    }
+
+    let mut rval = MatSimd::<T, O>::try_with_dimension(rows, columns).map_err(E::custom)?;
+    let mut flat = rval.flat_mut();
+
+    for (row, values) in data.chunks_exact(columns.max(1)).enumerate() {
+        for (column, x) in values.iter().enumerate() {
+            flat[(row, column)] = *x;
+        }
+    }
+
+    Ok(rval)
+}
+
+struct MatSimdVisitor<T, O>(PhantomData<(T, O)>);
+
+impl<'de, T, O> Visitor<'de> for MatSimdVisitor<T, O>
+where
+    T: Simd,
+    T::Element: Deserialize<'de>,
+    O: AccessStrategy,
+{
+    type Value = MatSimd<T, O>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("struct MatSimd")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let rows = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let columns = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        let data: Vec<T::Element> = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(2, &self))?;
+
+        from_row_major(rows, columns, &data)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut rows = None;
+        let mut columns = None;
+        let mut data: Option<Vec<T::Element>> = None;
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "rows" if rows.is_none() => rows = Some(map.next_value()?),
+                "columns" if columns.is_none() => columns = Some(map.next_value()?),
+                "data" if data.is_none() => data = Some(map.next_value()?),
+                "rows" | "columns" | "data" => return Err(de::Error::custom(format_args!("duplicate field `{key}`"))),
+                other => return Err(de::Error::unknown_field(other, FIELDS)),
+            }
+        }
+
+        let rows = rows.ok_or_else(|| de::Error::missing_field("rows"))?;
+        let columns = columns.ok_or_else(|| de::Error::missing_field("columns"))?;
+        let data = data.ok_or_else(|| de::Error::missing_field("data"))?;
+
+        from_row_major(rows, columns, &data)
+    }
+}
+
+impl<'de, T, O> Deserialize<'de> for MatSimd<T, O>
+where
+    T: Simd,
+    T::Element: Deserialize<'de>,
+    O: AccessStrategy,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_struct("MatSimd", FIELDS, MatSimdVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        arch::{f32x4, f32x8, f64x2, f64x4},
+        Columns, MatSimd, Padding, Rows, Tiled, VecSimd,
+    };
+
+    #[test]
+    fn vec_roundtrip() {
+        let mut v = VecSimd::<f64x4>::from_slice(&[1.0, 2.0, 3.0, 4.0, 5.0]);
+        v.set_padding(Padding::Value(9.0));
+
+        let json = serde_json::to_string(&v).unwrap();
+        let w: VecSimd<f64x2> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(json, "[1.0,2.0,3.0,4.0,5.0]");
+        assert_eq!(w.flat(), v.flat());
+    }
+
+    #[test]
+    fn mat_roundtrip() {
+        let mut m = MatSimd::<f32x8, Rows>::with_dimension(2, 3);
+
+        m.row_as_flat_mut(0).copy_from_slice(&[1.0, 2.0, 3.0]);
+        m.row_as_flat_mut(1).copy_from_slice(&[4.0, 5.0, 6.0]);
+
+        let json = serde_json::to_string(&m).unwrap();
+        let rows: MatSimd<f32x4, Rows> = serde_json::from_str(&json).unwrap();
+        let columns: MatSimd<f32x4, Columns> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(json, r#"{"rows":2,"columns":3,"data":[1.0,2.0,3.0,4.0,5.0,6.0]}"#);
+        assert_eq!(rows.row_as_flat(1), &[4.0, 5.0, 6.0]);
+        assert_eq!(columns.column_as_flat(2), &[3.0, 6.0]);
+        assert_eq!(serde_json::to_string(&columns).unwrap(), json);
+    }
+
+    #[test]
+    fn mat_validation() {
+        let short = serde_json::from_str::<MatSimd<f32x4, Rows>>(r#"{"rows":2,"columns":3,"data":[1.0,2.0]}"#);
+        let overflow = serde_json::from_str::<MatSimd<f32x4, Rows>>(r#"{"rows":18446744073709551615,"columns":2,"data":[]}"#);
+        let capacity = serde_json::from_str::<MatSimd<f32x4, Tiled<2, 2>>>(r#"{"rows":18446744073709551615,"columns":0,"data":[]}"#);
+        let missing = serde_json::from_str::<MatSimd<f32x4, Rows>>(r#"{"rows":0,"data":[]}"#);
+        let empty = serde_json::from_str::<MatSimd<f32x4, Columns>>(r#"{"rows":3,"columns":0,"data":[]}"#).unwrap();
+
+        assert!(short.unwrap_err().to_string().contains("invalid length 2, expected 6 elements"));
+        assert!(overflow.unwrap_err().to_string().contains("overflows"));
+        assert!(capacity.unwrap_err().to_string().contains("capacity overflow"));
+        assert!(missing.unwrap_err().to_string().contains("missing field `columns`"));
+        assert_eq!(empty.dimension(), (3, 0));
+    }
+}
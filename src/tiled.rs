@@ -0,0 +1,252 @@
+use std::slice::{ChunksExact, ChunksExactMut};
+
+use crate::{mat::private, traits::Simd, AccessStrategy, MatSimd};
+
+/// Packs blocks of `TR` rows by `TC` SIMD vectors together, see [`AccessStrategy`].
+///
+/// Each row is packed into SIMD vectors like with [`Rows`](crate::Rows), but the vectors of `TR`
+/// consecutive rows and `TC` consecutive vector columns are stored next to each other as one _tile_,
+/// row-major within the tile. Tiles themselves are stored row-major. This keeps elements that are
+/// close in both directions close in memory, e.g., for blocked matrix multiplication.
+///
+/// Rows and vectors per row are rounded up to whole tiles, the extra lanes, vectors and rows are
+/// padding. Rows are therefore not available as slices, use [`MatFlat`](crate::MatFlat) or the tiles
+/// of [`MatSimd::tile`] instead.
+///
+/// # Example
+///
+/// ```rust
+/// use simd_aligned::{MatSimd, arch::f32x4, Tiled};
+///
+/// let mut m = MatSimd::<f32x4, Tiled<2, 2>>::with_dimension(3, 10);
+/// m.flat_mut()[(2, 9)] = 1.0;
+///
+/// // 3 rows and 3 vectors per row are rounded up to 2 x 2 tiles of 2 x 2 vectors.
+/// assert_eq!(m.tile_dimension(), (2, 2));
+/// assert_eq!(m.tile(1, 1)[0].to_array()[1], 1.0);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Tiled<const TR: usize, const TC: usize>;
+
+impl<const TR: usize, const TC: usize> Tiled<TR, TC> {
+    const VALID: () = assert!(TR > 0 && TC > 0, "tiles must have at least one row and one column");
+
+    /// Number of SIMD vectors per tile.
+    const VECTORS: usize = TR * TC;
+}
+
+impl<const TR: usize, const TC: usize> private::Sealed for Tiled<TR, TC> {}
+
+impl<const TR: usize, const TC: usize> AccessStrategy for Tiled<TR, TC> {
+    #[inline]
+    fn flat_to_packed(row: usize, column: usize) -> (usize, usize) {
+        (row, column)
+    }
+
+    #[inline]
+    fn packed_to_flat(packed_row: usize, index: usize) -> (usize, usize) {
+        (packed_row, index)
+    }
+
+    #[inline]
+    fn vectors_per_row(row_length: usize, lanes: usize) -> Option<usize> {
+        let () = Self::VALID;
+
+        row_length.div_ceil(lanes).checked_next_multiple_of(TC)
+    }
+
+    #[inline]
+    fn storage_rows(rows: usize) -> Option<usize> {
+        let () = Self::VALID;
+
+        rows.checked_next_multiple_of(TR)
+    }
+
+    #[inline]
+    fn vector_index(packed_row: usize, vector: usize, vectors_per_row: usize) -> usize {
+        let tile = (packed_row / TR) * (vectors_per_row / TC) + vector / TC;
+
+        tile * Self::VECTORS + (packed_row % TR) * TC + vector % TC
+    }
+
+    #[inline]
+    fn vector_position(index: usize, vectors_per_row: usize) -> (usize, usize) {
+        let (tile, within) = (index / Self::VECTORS, index % Self::VECTORS);
+        let tiles_per_row = vectors_per_row / TC;
+
+        ((tile / tiles_per_row) * TR + within / TC, (tile % tiles_per_row) * TC + within % TC)
+    }
+}
+
+impl<T, const TR: usize, const TC: usize> MatSimd<T, Tiled<TR, TC>>
+where
+    T: Simd + Default + Clone,
+{
+    /// Returns the number of tiles as (`tile rows`, `tile columns`).
+    #[inline]
+    #[must_use]
+    pub const fn tile_dimension(&self) -> (usize, usize) {
+        // Rows are padded to whole tiles, see `Tiled::storage_rows`.
+        (self.simd_rows.rows.div_ceil(TR), self.simd_rows.vectors_per_row / TC)
+    }
+
+    /// Returns the `TR * TC` SIMD vectors of the tile at (`tile_row`, `tile_column`), row-major.
+    ///
+    /// Vector `r * TC + c` of the tile holds the elements of row `tile_row * TR + r`, starting at
+    /// column `(tile_column * TC + c) * T::LANES`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tile is outside [`tile_dimension`](Self::tile_dimension).
+    #[inline]
+    #[must_use]
+    pub fn tile(&self, tile_row: usize, tile_column: usize) -> &[T] {
+        let start = self.tile_start(tile_row, tile_column);
+
+        &self.simd_rows.data[start..start + Tiled::<TR, TC>::VECTORS]
+    }
+
+    /// Returns the SIMD vectors of the tile at (`tile_row`, `tile_column`), allowing modification.
+    ///
+    /// Padding is not restored automatically, see [`reset_padding`](Self::reset_padding).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tile is outside [`tile_dimension`](Self::tile_dimension).
+    #[inline]
+    pub fn tile_mut(&mut self, tile_row: usize, tile_column: usize) -> &mut [T] {
+        let start = self.tile_start(tile_row, tile_column);
+
+        &mut self.simd_rows.data[start..start + Tiled::<TR, TC>::VECTORS]
+    }
+
+    /// Returns an iterator over all tiles, row-major.
+    #[inline]
+    pub fn tile_iter(&self) -> ChunksExact<'_, T> {
+        self.simd_rows.data.chunks_exact(Tiled::<TR, TC>::VECTORS)
+    }
+
+    /// Returns an iterator over all tiles, row-major, allowing modification.
+    #[inline]
+    pub fn tile_iter_mut(&mut self) -> ChunksExactMut<'_, T> {
+        self.simd_rows.data.chunks_exact_mut(Tiled::<TR, TC>::VECTORS)
+    }
+
+    /// Returns a copy with `x` and `y` swapped, keeping the access strategy.
+    ///
+    /// The new matrix has dimension (`columns`, `rows`), and element `(y, x)` holds the value of
+    /// element `(x, y)` of this matrix.
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        let (rows, columns) = self.dimension();
+        let mut rval = Self::with_dimension(columns, rows);
+
+        self.simd_rows.transpose_into::<Tiled<TR, TC>>(&mut rval.simd_rows);
+        rval.set_padding(self.padding());
+        rval
+    }
+
+    fn tile_start(&self, tile_row: usize, tile_column: usize) -> usize {
+        let (tile_rows, tile_columns) = self.tile_dimension();
+
+        assert!(
+            tile_row < tile_rows && tile_column < tile_columns,
+            "tile ({tile_row}, {tile_column}) out of bounds for {tile_rows} x {tile_columns} tiles"
+        );
+
+        (tile_row * tile_columns + tile_column) * Tiled::<TR, TC>::VECTORS
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Tiled;
+    use crate::{arch::i32x4, MatSimd, Padding};
+
+    #[test]
+    fn layout() {
+        let mut m = MatSimd::<i32x4, Tiled<2, 2>>::with_dimension(3, 10);
+
+        for ((row, column), x) in &mut m.flat_mut() {
+            *x = i32::try_from(row * 100 + column).unwrap();
+        }
+
+        assert_eq!(m.dimension(), (3, 10));
+        assert_eq!(m.tile_dimension(), (2, 2));
+        assert_eq!(m.tile_iter().len(), 4);
+        assert_eq!(m.flat()[(2, 9)], 209);
+        assert_eq!(m.flat().get((3, 0)), None);
+        assert_eq!(m.flat().get((0, 10)), None);
+
+        // Tile (0, 1) holds rows 0 and 1 from column 8, the second vector of each row is padding.
+        assert_eq!(m.tile(0, 1)[0].to_array(), [8, 9, 0, 0]);
+        assert_eq!(m.tile(0, 1)[1].to_array(), [0; 4]);
+        assert_eq!(m.tile(0, 1)[2].to_array(), [108, 109, 0, 0]);
+        assert_eq!(m.tile(1, 0)[1].to_array(), [204, 205, 206, 207]);
+        assert_eq!(m.tile(1, 0)[2].to_array(), [0; 4]);
+
+        let expected = (0..3).flat_map(|row| (0..10).map(move |column| (row, column)));
+        let mut found = m.flat().iter().map(|(index, _)| index).collect::<Vec<_>>();
+        found.sort_unstable();
+
+        assert_eq!(m.flat().iter().len(), 30);
+        assert!(found.into_iter().eq(expected));
+    }
+
+    #[test]
+    fn padding_and_transpose() {
+        let mut m = MatSimd::<i32x4, Tiled<2, 2>>::with_dimension(3, 5);
+
+        m.flat_mut()[(2, 4)] = 7;
+        m.set_padding(Padding::Value(-1));
+
+        assert_eq!(m.tile(1, 0)[0].to_array(), [0, 0, 0, 0]);
+        assert_eq!(m.tile(1, 0)[1].to_array(), [7, -1, -1, -1]);
+        assert_eq!(m.tile(1, 0)[2].to_array(), [-1; 4]);
+
+        m.tile_mut(1, 0)[2] = i32x4::splat(5);
+        m.reset_padding();
+        assert_eq!(m.tile(1, 0)[2].to_array(), [-1; 4]);
+
+        let t = m.transpose();
+
+        assert_eq!(t.dimension(), (5, 3));
+        assert_eq!(t.flat()[(4, 2)], 7);
+        assert_eq!(t.padding(), Padding::Value(-1));
+        assert_eq!(t.tile(0, 0)[1].to_array(), [-1; 4]);
+    }
+
+    #[test]
+    fn transpose() {
+        let mut m = MatSimd::<i32x4, Tiled<2, 3>>::with_dimension(9, 17);
+
+        for ((row, column), x) in &mut m.flat_mut() {
+            *x = i32::try_from(row * 100 + column).unwrap();
+        }
+
+        let t = m.transpose();
+
+        assert_eq!(t.dimension(), (17, 9));
+        assert!(t.flat().iter().all(|((row, column), x)| *x == i32::try_from(column * 100 + row).unwrap()));
+        assert_eq!(t.tile(8, 0)[3].to_array(), [0; 4]);
+    }
+
+    #[test]
+    fn empty() {
+        let m = MatSimd::<i32x4, Tiled<2, 2>>::with_dimension(3, 0);
+        let t = m.transpose();
+
+        assert_eq!(m.tile_dimension(), (2, 0));
+        assert_eq!(m.tile_iter().len(), 0);
+        assert_eq!(t.dimension(), (0, 3));
+        assert_eq!(t.tile_dimension(), (0, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "tile (2, 0) out of bounds for 2 x 1 tiles")]
+    fn tile_out_of_bounds() {
+        let m = MatSimd::<i32x4, Tiled<2, 2>>::with_dimension(3, 5);
+
+        _ = m.tile(2, 0);
+    }
+}
@@ -1,10 +1,19 @@
 //! Unified views on SIMD types.
+use std::{
+    fmt::Debug,
+    ops::{Add, Div, Mul, Sub},
+};
 
 /// This is mostly copy-paste from `packed_simd`, where this trait is unfortunately
 /// sealed right now. In the future this might come from `std::simd`.
-pub trait Simd {
+///
+/// All SIMD types support lane-wise addition and subtraction. Multiplication is not a supertrait
+/// since `i8x__` and `u8x__` don't support it, so generic code needing it should additionally
+/// require `Mul<Output = Self>`. Integer arithmetic wraps on overflow, in lanes as well as in
+/// reductions.
+pub trait Simd: Copy + Default + Debug + Add<Output = Self> + Sub<Output = Self> {
     /// Element type of the SIMD vector
-    type Element;
+    type Element: Copy + Default + Debug + PartialOrd + Add<Output = Self::Element> + Mul<Output = Self::Element>;
     /// The number of elements in the SIMD vector.
     const LANES: usize;
     /// The type: `[u32; Self::N]`.
@@ -13,8 +22,95 @@ pub trait Simd {
     /// Added for convenience
     fn splat(t: Self::Element) -> Self;
 
+    /// Creates a vector from an array of `LANES` elements.
+    fn from_array(array: Self::LanesType) -> Self;
+
+    /// Loads the first `LANES` elements of `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is shorter than `LANES`.
+    #[must_use]
+    fn from_slice(slice: &[Self::Element]) -> Self {
+        let mut rval = Self::default();
+        rval.as_array_mut().copy_from_slice(&slice[..Self::LANES]);
+        rval
+    }
+
+    /// Stores all lanes into the first `LANES` elements of `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is shorter than `LANES`.
+    fn write_to_slice(&self, slice: &mut [Self::Element]) {
+        slice[..Self::LANES].copy_from_slice(self.as_array());
+    }
+
     /// Added for convenience
     fn as_array(&self) -> &[Self::Element];
 
+    /// Returns the lanes as a mutable slice.
+    fn as_array_mut(&mut self) -> &mut [Self::Element];
+
+    /// Lane-wise minimum. For floating point lanes, a `NaN` in one operand yields the other one.
+    #[must_use]
+    fn min(self, other: Self) -> Self;
+
+    /// Lane-wise maximum. For floating point lanes, a `NaN` in one operand yields the other one.
+    #[must_use]
+    fn max(self, other: Self) -> Self;
+
+    /// Adds all lanes in ascending order. Integer sums wrap on overflow, also in debug builds.
     fn sum(&self) -> Self::Element;
+
+    /// Multiplies all lanes in ascending order.
+    fn reduce_product(&self) -> Self::Element;
+
+    /// Smallest lane. For floating point lanes, `NaN` is ignored unless all lanes are `NaN`.
+    fn reduce_min(&self) -> Self::Element;
+
+    /// Largest lane. For floating point lanes, `NaN` is ignored unless all lanes are `NaN`.
+    fn reduce_max(&self) -> Self::Element;
+}
+
+/// Types that kernels may share with worker threads.
+///
+/// With the `rayon` feature, large inputs are split across threads, so this requires `Send + Sync`.
+/// Without it, all types implement this trait.
+#[cfg(feature = "rayon")]
+pub trait MaybeSendSync: Send + Sync {}
+
+#[cfg(feature = "rayon")]
+impl<T: Send + Sync + ?Sized> MaybeSendSync for T {}
+
+/// Types that kernels may share with worker threads.
+///
+/// With the `rayon` feature, large inputs are split across threads, so this requires `Send + Sync`.
+/// Without it, all types implement this trait.
+#[cfg(not(feature = "rayon"))]
+pub trait MaybeSendSync {}
+
+#[cfg(not(feature = "rayon"))]
+impl<T: ?Sized> MaybeSendSync for T {}
+
+/// Scalar floating point types, i.e., `f32` and `f64`.
+pub trait Float: Copy + Default + Debug + PartialOrd + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> {
+    /// Absolute value.
+    #[must_use]
+    fn abs(self) -> Self;
+
+    /// Square root.
+    #[must_use]
+    fn sqrt(self) -> Self;
+
+    /// Converts a count, rounding to the nearest representable value.
+    #[must_use]
+    fn from_usize(x: usize) -> Self;
+}
+
+/// SIMD vectors with floating point lanes, i.e., `f32x__` and `f64x__`.
+pub trait SimdFloat: Simd<Element: Float> + Mul<Output = Self> + Div<Output = Self> {
+    /// Lane-wise absolute value.
+    #[must_use]
+    fn abs(self) -> Self;
 }
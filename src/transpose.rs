@@ -0,0 +1,319 @@
+//! In-register transposes of square tiles of `LANES x LANES` elements.
+//!
+//! A tile consists of `LANES` SIMD vectors from consecutive rows. It's transposed with `log2(LANES)`
+//! rounds of unpack instructions, each interleaving pairs of vectors at twice the granularity of
+//! the round before. For 256 bit vectors, unpacks work within 128 bit halves, so the last round
+//! swaps halves instead. The rows of the result come out shuffled, see [`source_column`], and are
+//! stored to their place directly.
+//!
+//! Unpacks only move bits, so one kernel per vector and element size covers all element types.
+use crate::{
+    dispatch::{self, Level},
+    traits::Simd,
+};
+
+/// Transposes a tile of `T::LANES x T::LANES` elements from `source` into `target`.
+///
+/// Row `i` of the tile is vector `source_index(i)` of `source`. After the transpose, column `c`
+/// of the tile is stored as vector `target_index(c)` of `target`.
+///
+/// Returns `false` without touching `target` if there is no in-register path for `T` at the
+/// current [`dispatch::level`](crate::dispatch::level), in which case the caller copies elements.
+///
+/// # Panics
+///
+/// Panics if an index is out of bounds.
+#[inline]
+pub fn transpose_tile<T>(source: &[T], source_index: impl Fn(usize) -> usize, target: &mut [T], target_index: impl Fn(usize) -> usize) -> bool
+where
+    T: Simd,
+{
+    transpose_tile_at(dispatch::level(), source, source_index, target, target_index)
+}
+
+/// Like [`transpose_tile`], but for the given `level`, which must not exceed [`dispatch::detected`].
+#[inline]
+fn transpose_tile_at<T>(level: Level, source: &[T], source_index: impl Fn(usize) -> usize, target: &mut [T], target_index: impl Fn(usize) -> usize) -> bool
+where
+    T: Simd,
+{
+    debug_assert!(level <= dispatch::detected());
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        x86::transpose_tile(level, source, source_index, target, target_index)
+    }
+
+    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+    {
+        _ = (level, source, source_index, target, target_index);
+        false
+    }
+}
+
+/// Returns the column of the tile that ends up in output row `row` after `rounds` unpack rounds.
+///
+/// Each round moves the highest column bit (within a 128 bit half for `in_lane` rounds, except the
+/// last) into the row index, and the lowest row bit into the column index. After all rounds the
+/// column index holds the original row, and bit `i` of the output row holds the column bit taken
+/// in round `i`.
+const fn source_column(row: usize, rounds: usize, in_lane: bool) -> usize {
+    // Bit `i` of the current column index holds original column bit `origin[i]`. Positions below
+    // the current round hold row bits and are never taken again.
+    let mut origin = [0, 1, 2, 3, 4, 5, 6, 7];
+    let mut column = 0;
+    let mut round = 0;
+
+    while round < rounds {
+        let top = if in_lane && round + 1 < rounds { rounds - 2 } else { rounds - 1 };
+        let taken = origin[top];
+        let mut i = top;
+
+        while i > round {
+            origin[i] = origin[i - 1];
+            i -= 1;
+        }
+
+        column |= ((row >> round) & 1) << taken;
+        round += 1;
+    }
+
+    column
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod x86 {
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86 as arch;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64 as arch;
+    use std::mem::size_of;
+
+    use arch::{
+        __m128i, __m256i, _mm256_loadu_si256, _mm256_permute2x128_si256, _mm256_setzero_si256, _mm256_storeu_si256, _mm256_unpackhi_epi16, _mm256_unpackhi_epi32,
+        _mm256_unpackhi_epi64, _mm256_unpackhi_epi8, _mm256_unpacklo_epi16, _mm256_unpacklo_epi32, _mm256_unpacklo_epi64, _mm256_unpacklo_epi8, _mm_loadu_si128, _mm_setzero_si128,
+        _mm_storeu_si128, _mm_unpackhi_epi16, _mm_unpackhi_epi32, _mm_unpackhi_epi64, _mm_unpackhi_epi8, _mm_unpacklo_epi16, _mm_unpacklo_epi32, _mm_unpacklo_epi64,
+        _mm_unpacklo_epi8,
+    };
+
+    use super::source_column;
+    use crate::{dispatch::Level, traits::Simd};
+
+    pub fn transpose_tile<T: Simd>(level: Level, source: &[T], source_index: impl Fn(usize) -> usize, target: &mut [T], target_index: impl Fn(usize) -> usize) -> bool {
+        let element = size_of::<T::Element>();
+
+        // Custom `Simd` types might not consist of exactly `LANES` elements.
+        if T::LANES * element != size_of::<T>() {
+            return false;
+        }
+
+        // SAFETY: The caller ensures `level` is supported, and `Sse41` implies SSE2.
+        unsafe {
+            match (size_of::<T>(), element) {
+                (16, 1) if level >= Level::Sse41 => tile_128::<T, 1, 16, 4>(source, source_index, target, target_index),
+                (16, 2) if level >= Level::Sse41 => tile_128::<T, 2, 8, 3>(source, source_index, target, target_index),
+                (16, 4) if level >= Level::Sse41 => tile_128::<T, 4, 4, 2>(source, source_index, target, target_index),
+                (16, 8) if level >= Level::Sse41 => tile_128::<T, 8, 2, 1>(source, source_index, target, target_index),
+                (32, 1) if level >= Level::Avx2 => tile_256::<T, 1, 32, 5>(source, source_index, target, target_index),
+                (32, 2) if level >= Level::Avx2 => tile_256::<T, 2, 16, 4>(source, source_index, target, target_index),
+                (32, 4) if level >= Level::Avx2 => tile_256::<T, 4, 8, 3>(source, source_index, target, target_index),
+                (32, 8) if level >= Level::Avx2 => tile_256::<T, 8, 4, 2>(source, source_index, target, target_index),
+                _ => return false,
+            }
+        }
+
+        true
+    }
+
+    /// Interleaves the lower halves of the `width` byte blocks of `x` and `y`.
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn unpack_lo_128(width: usize, x: __m128i, y: __m128i) -> __m128i {
+        match width {
+            1 => _mm_unpacklo_epi8(x, y),
+            2 => _mm_unpacklo_epi16(x, y),
+            4 => _mm_unpacklo_epi32(x, y),
+            _ => _mm_unpacklo_epi64(x, y),
+        }
+    }
+
+    /// Interleaves the upper halves of the `width` byte blocks of `x` and `y`.
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn unpack_hi_128(width: usize, x: __m128i, y: __m128i) -> __m128i {
+        match width {
+            1 => _mm_unpackhi_epi8(x, y),
+            2 => _mm_unpackhi_epi16(x, y),
+            4 => _mm_unpackhi_epi32(x, y),
+            _ => _mm_unpackhi_epi64(x, y),
+        }
+    }
+
+    /// Interleaves the lower halves of the `width` byte blocks of `x` and `y`, per 128 bit half.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn unpack_lo_256(width: usize, x: __m256i, y: __m256i) -> __m256i {
+        match width {
+            1 => _mm256_unpacklo_epi8(x, y),
+            2 => _mm256_unpacklo_epi16(x, y),
+            4 => _mm256_unpacklo_epi32(x, y),
+            _ => _mm256_unpacklo_epi64(x, y),
+        }
+    }
+
+    /// Interleaves the upper halves of the `width` byte blocks of `x` and `y`, per 128 bit half.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn unpack_hi_256(width: usize, x: __m256i, y: __m256i) -> __m256i {
+        match width {
+            1 => _mm256_unpackhi_epi8(x, y),
+            2 => _mm256_unpackhi_epi16(x, y),
+            4 => _mm256_unpackhi_epi32(x, y),
+            _ => _mm256_unpackhi_epi64(x, y),
+        }
+    }
+
+    /// Transposes a tile of `N` 128 bit vectors with elements of `E` bytes, where `N = 2^ROUNDS`.
+    ///
+    /// # Safety
+    ///
+    /// The CPU must support SSE2, and `T` must consist of exactly `N` elements of `E` bytes.
+    #[target_feature(enable = "sse2")]
+    unsafe fn tile_128<T: Simd, const E: usize, const N: usize, const ROUNDS: usize>(
+        source: &[T],
+        source_index: impl Fn(usize) -> usize,
+        target: &mut [T],
+        target_index: impl Fn(usize) -> usize,
+    ) {
+        let mut rows = [_mm_setzero_si128(); N];
+
+        for (i, row) in rows.iter_mut().enumerate() {
+            *row = _mm_loadu_si128(source[source_index(i)].as_array().as_ptr().cast());
+        }
+
+        for round in 0..ROUNDS {
+            let previous = rows;
+
+            for k in 0..N / 2 {
+                rows[k] = unpack_lo_128(E << round, previous[2 * k], previous[2 * k + 1]);
+                rows[k + N / 2] = unpack_hi_128(E << round, previous[2 * k], previous[2 * k + 1]);
+            }
+        }
+
+        for (i, row) in rows.iter().enumerate() {
+            _mm_storeu_si128(target[target_index(source_column(i, ROUNDS, false))].as_array_mut().as_mut_ptr().cast(), *row);
+        }
+    }
+
+    /// Transposes a tile of `N` 256 bit vectors with elements of `E` bytes, where `N = 2^ROUNDS`.
+    ///
+    /// # Safety
+    ///
+    /// The CPU must support AVX2, and `T` must consist of exactly `N` elements of `E` bytes.
+    #[target_feature(enable = "avx2")]
+    unsafe fn tile_256<T: Simd, const E: usize, const N: usize, const ROUNDS: usize>(
+        source: &[T],
+        source_index: impl Fn(usize) -> usize,
+        target: &mut [T],
+        target_index: impl Fn(usize) -> usize,
+    ) {
+        let mut rows = [_mm256_setzero_si256(); N];
+
+        for (i, row) in rows.iter_mut().enumerate() {
+            *row = _mm256_loadu_si256(source[source_index(i)].as_array().as_ptr().cast());
+        }
+
+        for round in 0..ROUNDS {
+            let previous = rows;
+
+            for k in 0..N / 2 {
+                let (x, y) = (previous[2 * k], previous[2 * k + 1]);
+
+                (rows[k], rows[k + N / 2]) = if round + 1 < ROUNDS {
+                    (unpack_lo_256(E << round, x, y), unpack_hi_256(E << round, x, y))
+                } else {
+                    (_mm256_permute2x128_si256::<0x20>(x, y), _mm256_permute2x128_si256::<0x31>(x, y))
+                };
+            }
+        }
+
+        for (i, row) in rows.iter().enumerate() {
+            _mm256_storeu_si256(target[target_index(source_column(i, ROUNDS, true))].as_array_mut().as_mut_ptr().cast(), *row);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{source_column, transpose_tile_at};
+    use crate::{
+        arch::{f32x4, f32x8, f64x2, f64x4, i16x16, i16x8, i8x32, u8x16},
+        dispatch::{self, Level},
+        traits::Simd,
+    };
+
+    #[test]
+    fn columns() {
+        assert_eq!((0..4).map(|i| source_column(i, 2, false)).collect::<Vec<_>>(), [0, 2, 1, 3]);
+        assert_eq!((0..8).map(|i| source_column(i, 3, true)).collect::<Vec<_>>(), [0, 2, 1, 3, 4, 6, 5, 7]);
+        assert_eq!(source_column(1, 1, false), 1);
+    }
+
+    /// Checks a tile stored with gaps between rows against the definition, for all elements of `T`.
+    fn check<T: Simd>(level: Level, element: impl Fn(usize) -> T::Element) -> bool
+    where
+        T::Element: PartialEq,
+    {
+        let lanes = T::LANES;
+        let mut source = vec![T::default(); 2 * lanes];
+        let mut target = vec![T::default(); 3 * lanes];
+
+        for (i, x) in source.iter_mut().enumerate() {
+            for (j, lane) in x.as_array_mut().iter_mut().enumerate() {
+                *lane = element(i * lanes + j);
+            }
+        }
+
+        if !transpose_tile_at(level, &source, |r| 2 * r + 1, &mut target, |c| 3 * c) {
+            return false;
+        }
+
+        for r in 0..lanes {
+            for c in 0..lanes {
+                assert!(target[3 * c].as_array()[r] == source[2 * r + 1].as_array()[c]);
+            }
+        }
+
+        true
+    }
+
+    #[test]
+    fn tiles() {
+        for level in [Level::Baseline, Level::Sse41, Level::Avx2] {
+            if level > dispatch::detected() {
+                continue;
+            }
+
+            #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap, clippy::cast_precision_loss)]
+            let found = [
+                check::<u8x16>(level, |i| i as u8),
+                check::<i16x8>(level, |i| i as i16),
+                check::<f32x4>(level, |i| i as f32),
+                check::<f64x2>(level, |i| i as f64),
+                check::<i8x32>(level, |i| i as i8),
+                check::<i16x16>(level, |i| i as i16),
+                check::<f32x8>(level, |i| i as f32),
+                check::<f64x4>(level, |i| i as f64),
+            ];
+
+            let expected = match level {
+                _ if cfg!(not(any(target_arch = "x86", target_arch = "x86_64"))) => 0,
+                Level::Baseline => 0,
+                Level::Sse41 => 4,
+                _ => 8,
+            };
+
+            assert_eq!(found.iter().filter(|x| **x).count(), expected);
+        }
+    }
+}
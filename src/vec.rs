@@ -1,11 +1,8 @@
 use std::ops::{Deref, DerefMut, Index, IndexMut};
 
-use crate::traits::Simd;
+use crate::{traits::Simd, Error, Padding};
 
-use super::{
-    conversion::{simd_container_flat_slice, simd_container_flat_slice_mut},
-    packed::PackedMxN,
-};
+use super::packed::PackedMxN;
 
 /// A dynamic (heap allocated) vector aligned for fast and safe SIMD access that also provides a
 /// flat view on its data.
@@ -14,12 +11,12 @@ use super::{
 ///
 /// ```rust
 ///
-/// // Create a vector of f64x__ elements that, in total, will hold space
-/// // for at least 4 f64 values. Internally this might be one f64x4, two f64x2,
-/// // or one f64x8 where the 2nd half is hidden, depending on the current architecture.
-/// use simd_aligned::{VecSimd, arch::f64x4};
+/// // Create a vector of f64s elements that, in total, will hold space
+/// // for at least 4 f64 values. Internally this might be one f64x4 or two f64x2,
+/// // depending on the current architecture.
+/// use simd_aligned::{VecSimd, arch::f64s};
 ///
-/// let mut v = VecSimd::<f64x4>::with(0_f64, 4);
+/// let mut v = VecSimd::<f64s>::with(0_f64, 4);
 ///
 /// // Get a 'flat view' (&[f64]) into the SIMD vectors and fill it.
 /// v.flat_mut().clone_from_slice(&[0.0, 1.0, 2.0, 3.0]);
@@ -38,6 +35,13 @@ where
     T: Simd + Default + Clone,
 {
     /// Produce a [`VecSimd`] with the given element `t` as default and a flat size of `size`.
+    ///
+    /// Padding lanes follow the default [`Padding`] policy, so they hold `0` rather than `t`. Use
+    /// [`set_padding`](Self::set_padding) with [`Padding::Value`] to fill them with `t`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is too large, use [`try_with`](Self::try_with) to handle this.
     #[inline]
     pub fn with(t: T::Element, size: usize) -> Self {
         Self {
@@ -45,17 +49,251 @@ where
         }
     }
 
+    /// Like [`with`](Self::with), but returns an error instead of panicking if `size` is too large.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use simd_aligned::{Error, VecSimd, arch::f32x4};
+    ///
+    /// let v = VecSimd::<f32x4>::try_with(1.0, 5)?;
+    ///
+    /// assert_eq!(v.flat(), &[1.0; 5]);
+    /// assert_eq!(VecSimd::<f32x4>::try_with(0.0, usize::MAX).unwrap_err(), Error::CapacityOverflow);
+    /// # Ok::<(), Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::CapacityOverflow`] if the size doesn't fit the address space, and
+    /// [`Error::AllocationFailed`] if the memory can't be allocated.
+    #[inline]
+    pub fn try_with(t: T::Element, size: usize) -> Result<Self, Error> {
+        Ok(Self {
+            simd_rows: PackedMxN::try_with(T::splat(t), 1, size)?,
+        })
+    }
+
+    /// Produce a [`VecSimd`] of flat size `size` where all lanes are `T::default()`.
+    #[inline]
+    pub(crate) fn with_default(size: usize) -> Self {
+        Self {
+            simd_rows: PackedMxN::with(T::default(), 1, size),
+        }
+    }
+
+    /// Produce a [`VecSimd`] holding a copy of the flat `slice`.
+    ///
+    /// Lanes of the last SIMD vector not covered by `slice` follow the default [`Padding`] policy.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use simd_aligned::{VecSimd, arch::f32x4};
+    ///
+    /// let v = VecSimd::<f32x4>::from_slice(&[1.0, 2.0, 3.0, 4.0, 5.0]);
+    ///
+    /// assert_eq!(v.flat(), &[1.0, 2.0, 3.0, 4.0, 5.0]);
+    /// assert_eq!(v.len(), 2);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_slice(slice: &[T::Element]) -> Self {
+        let mut rval = Self::with_default(slice.len());
+
+        rval.flat_mut().copy_from_slice(slice);
+        rval
+    }
+
+    /// Appends `value` to the end of the flat view, adding a new SIMD vector only if all
+    /// lanes of the last one are in use.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use simd_aligned::{VecSimd, arch::f32x4};
+    ///
+    /// let mut v = VecSimd::<f32x4>::with(0.0, 0);
+    ///
+    /// v.push(1.0);
+    /// v.push(2.0);
+    ///
+    /// assert_eq!(v.flat(), &[1.0, 2.0]);
+    /// assert_eq!(v.len(), 1);
+    /// ```
+    #[inline]
+    pub fn push(&mut self, value: T::Element) {
+        let length = self.simd_rows.row_length;
+
+        if length % T::LANES == 0 {
+            self.simd_rows.set_single_row_length(length + 1);
+        } else {
+            // The lane was padding, and the lanes after it stay padding.
+            self.simd_rows.row_length = length + 1;
+        }
+
+        self.flat_mut()[length] = value;
+    }
+
+    /// Removes the last element of the flat view and returns it, or `None` if empty.
+    ///
+    /// The freed lane becomes padding and is reset according to the [`Padding`] policy.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T::Element> {
+        let length = self.simd_rows.row_length.checked_sub(1)?;
+        let value = self.flat()[length];
+
+        self.truncate(length);
+
+        Some(value)
+    }
+
+    /// Resizes the flat view to `length` elements, filling new slots with `value`.
+    #[inline]
+    pub fn resize(&mut self, length: usize, value: T::Element) {
+        let old_length = self.simd_rows.row_length;
+
+        if length < old_length {
+            self.truncate(length);
+        } else {
+            self.simd_rows.set_single_row_length(length);
+            self.flat_mut()[old_length..].fill(value);
+        }
+    }
+
+    /// Shortens the flat view to `length` elements, dropping SIMD vectors that are no longer
+    /// needed. Has no effect if `length` is greater than the current flat length.
+    ///
+    /// Freed lanes in the last SIMD vector are reset according to the [`Padding`] policy.
+    #[inline]
+    pub fn truncate(&mut self, length: usize) {
+        if length >= self.simd_rows.row_length {
+            return;
+        }
+
+        self.simd_rows.set_single_row_length(length);
+    }
+
+    /// Removes all elements, keeping the allocated capacity.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Reserves capacity for at least `additional` more flat elements.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        let needed = PackedMxN::<T>::vectors_for_length(self.simd_rows.row_length + additional);
+        let additional_vectors = needed.saturating_sub(self.simd_rows.data.len());
+
+        self.simd_rows.data.reserve(additional_vectors);
+    }
+
+    /// Returns the number of flat elements this [`VecSimd`] can hold without reallocating.
+    #[inline]
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.simd_rows.data.capacity() * T::LANES
+    }
+
+    /// Returns the current [`Padding`] policy.
+    #[inline]
+    #[must_use]
+    pub const fn padding(&self) -> Padding<T::Element> {
+        self.simd_rows.padding
+    }
+
+    /// Changes the [`Padding`] policy and applies it to the last SIMD vector.
+    #[inline]
+    pub fn set_padding(&mut self, padding: Padding<T::Element>) {
+        self.simd_rows.set_padding(padding);
+    }
+
+    /// Restores the [`Padding`] policy, e.g., after SIMD vectors were written through `DerefMut`.
+    #[inline]
+    pub fn reset_padding(&mut self) {
+        self.simd_rows.reset_padding();
+    }
+
     /// Get a flat view for this [`VecSimd`].
     #[inline]
     #[must_use]
     pub fn flat(&self) -> &[T::Element] {
-        simd_container_flat_slice(&self.simd_rows.data[..], self.simd_rows.row_length)
+        self.simd_rows.row_as_flat(0)
     }
 
     /// Get a flat, mutable view for this [`VecSimd`].
     #[inline]
     pub fn flat_mut(&mut self) -> &mut [T::Element] {
-        simd_container_flat_slice_mut(&mut self.simd_rows.data[..], self.simd_rows.row_length)
+        self.simd_rows.row_as_flat_mut(0)
+    }
+}
+
+impl<T> AsRef<[T::Element]> for VecSimd<T>
+where
+    T: Simd + Default + Clone,
+{
+    #[inline]
+    fn as_ref(&self) -> &[T::Element] {
+        self.flat()
+    }
+}
+
+impl<T> From<Vec<T::Element>> for VecSimd<T>
+where
+    T: Simd + Default + Clone,
+{
+    #[inline]
+    fn from(vec: Vec<T::Element>) -> Self {
+        Self::from_slice(&vec)
+    }
+}
+
+impl<T> From<&[T::Element]> for VecSimd<T>
+where
+    T: Simd + Default + Clone,
+{
+    #[inline]
+    fn from(slice: &[T::Element]) -> Self {
+        Self::from_slice(slice)
+    }
+}
+
+impl<T> FromIterator<T::Element> for VecSimd<T>
+where
+    T: Simd + Default + Clone,
+{
+    fn from_iter<I: IntoIterator<Item = T::Element>>(iter: I) -> Self {
+        let mut rval = Self::with_default(0);
+        rval.extend(iter);
+        rval
+    }
+}
+
+impl<T> Extend<T::Element> for VecSimd<T>
+where
+    T: Simd + Default + Clone,
+{
+    fn extend<I: IntoIterator<Item = T::Element>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+
+        self.reserve(iter.size_hint().0);
+
+        let mut length = self.simd_rows.row_length;
+
+        // Write into the lanes past the flat view and apply the padding policy once at the end.
+        for value in iter {
+            let (vector, lane) = (length / T::LANES, length % T::LANES);
+
+            if vector == self.simd_rows.data.len() {
+                self.simd_rows.data.push(T::default());
+            }
+
+            self.simd_rows.data[vector].as_array_mut()[lane] = value;
+            length += 1;
+        }
+
+        self.simd_rows.set_single_row_length(length);
     }
 }
 
@@ -102,9 +340,13 @@ where
 }
 
 #[cfg(test)]
+#[allow(clippy::semicolon_if_nothing_returned, clippy::legacy_numeric_constants)] // Keeps the original tests as written.
 mod test {
     use super::VecSimd;
-    use crate::arch::f32x4;
+    use crate::{
+        arch::{f32x4, i32x4},
+        Padding,
+    };
 
     #[test]
     fn allocation_size() {
@@ -143,4 +385,101 @@ mod test {
         let v = VecSimd::<f32x4>::with(0.0f32, 16);
         assert_eq!(&v[0], &v[0]);
     }
+
+    #[test]
+    fn from_slice() {
+        let v = VecSimd::<i32x4>::from_slice(&[1, 2, 3, 4, 5]);
+
+        assert_eq!(v.flat(), &[1, 2, 3, 4, 5]);
+        assert_eq!(v.len(), 2);
+        assert_eq!(v[1].to_array(), [5, 0, 0, 0]);
+
+        let v = VecSimd::<f32x4>::from(vec![1.0, 2.0]);
+
+        assert_eq!(v.flat(), &[1.0, 2.0]);
+    }
+
+    #[test]
+    fn push_pop() {
+        let mut v = VecSimd::<i32x4>::with(0, 0);
+
+        for i in 0..5 {
+            v.push(i);
+        }
+
+        assert_eq!(v.flat(), &[0, 1, 2, 3, 4]);
+        assert_eq!(v.len(), 2);
+        assert!(v.capacity() >= 8);
+
+        assert_eq!(v.pop(), Some(4));
+        assert_eq!(v.len(), 1);
+        assert_eq!(v.pop(), Some(3));
+        assert_eq!(v[0].to_array(), [0, 1, 2, 0]);
+
+        v.clear();
+
+        assert_eq!(v.pop(), None);
+        assert_eq!(v.len(), 0);
+    }
+
+    #[test]
+    fn resize_truncate() {
+        let mut v = VecSimd::<i32x4>::with(1, 2);
+
+        v.reserve(10);
+        assert!(v.capacity() >= 12);
+
+        v.resize(6, 7);
+        assert_eq!(v.flat(), &[1, 1, 7, 7, 7, 7]);
+
+        v.truncate(3);
+        assert_eq!(v.flat(), &[1, 1, 7]);
+        assert_eq!(v[0].to_array(), [1, 1, 7, 0]);
+        assert_eq!(v.len(), 1);
+
+        v.resize(2, 0);
+        assert_eq!(v.flat(), &[1, 1]);
+    }
+
+    #[test]
+    fn padding() {
+        let mut v = VecSimd::<i32x4>::with(1, 3);
+
+        assert_eq!(v[0].to_array(), [1, 1, 1, 0]);
+
+        v.set_padding(Padding::Value(-1));
+        v.push(2);
+        v.push(3);
+
+        assert_eq!(v.padding(), Padding::Value(-1));
+        assert_eq!(v[1].to_array(), [3, -1, -1, -1]);
+
+        v[1] = i32x4::splat(5);
+        v.reset_padding();
+
+        assert_eq!(v[1].to_array(), [5, -1, -1, -1]);
+
+        v.resize(6, 7);
+
+        assert_eq!(v[1].to_array(), [5, 7, -1, -1]);
+    }
+
+    #[test]
+    fn from_iter_extend() {
+        let mut v = (0..3).collect::<VecSimd<i32x4>>();
+
+        assert_eq!(v.flat(), &[0, 1, 2]);
+
+        v.extend([3, 4, 5]);
+
+        assert_eq!(v.flat(), &[0, 1, 2, 3, 4, 5]);
+        assert_eq!(v.len(), 2);
+        assert_eq!(v[1].to_array(), [4, 5, 0, 0]);
+
+        v.set_padding(Padding::Value(-1));
+        v.extend([6, 7, 8]);
+
+        assert_eq!(v.flat(), &[0, 1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(v[2].to_array(), [8, -1, -1, -1]);
+    }
 }
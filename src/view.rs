@@ -0,0 +1,396 @@
+use std::ops::Deref;
+
+use crate::{
+    format::{self, packed_as_bytes, packed_from_bytes, Header, Storable, HEADER_SIZE, LAYOUT_COLUMNS, LAYOUT_ROWS, LAYOUT_VEC},
+    traits::Simd,
+    AccessStrategy, Columns, Error, MatSimdRef, Padding, Rows, VecSimdRef,
+};
+
+/// A parsed header, with its bytes and the SIMD vectors following it.
+type Parsed<'a, T> = (Header<T>, &'a [u8; HEADER_SIZE], &'a [T]);
+
+/// Parses a file in the [binary format](crate::format) and borrows its header and SIMD vectors
+/// from `bytes`.
+///
+/// Only the header, size and alignment are checked, the data isn't read.
+fn parse<T: Storable>(bytes: &[u8], layout: u8) -> Result<Parsed<'_, T>, Error> {
+    let (header_bytes, data) = bytes.split_first_chunk::<HEADER_SIZE>().ok_or(Error::SizeMismatch {
+        expected: HEADER_SIZE,
+        actual: bytes.len(),
+    })?;
+
+    let header = Header::<T>::parse(*header_bytes, layout)?;
+
+    if data.len() != header.data_size {
+        return Err(Error::SizeMismatch {
+            expected: HEADER_SIZE + header.data_size,
+            actual: bytes.len(),
+        });
+    }
+
+    let vectors = packed_from_bytes(data)?;
+
+    Ok((header, header_bytes, vectors))
+}
+
+/// A read-only [`VecSimd`](crate::VecSimd) borrowed from a file in the [binary format](crate::format), e.g., a memory-mapped one.
+///
+/// The SIMD vectors are used in place, so the bytes must be aligned for `T`. Memory mappings start at
+/// page boundaries and the header is [`HEADER_SIZE`] bytes, so this holds for mapped files. Provides
+/// the API of [`VecSimdRef`] through `Deref`.
+#[derive(Clone, Copy, Debug)]
+pub struct VecSimdView<'a, T>
+where
+    T: Simd,
+{
+    vector: VecSimdRef<'a, T>,
+    padding: Padding<T::Element>,
+    header: &'a [u8; HEADER_SIZE],
+}
+
+impl<'a, T> VecSimdView<'a, T>
+where
+    T: Storable,
+{
+    /// Validates the header of `bytes` and borrows the vector they hold.
+    ///
+    /// The data itself isn't read, so this is cheap even for large mapped files. Use
+    /// [`verify`](Self::verify) to also check the checksum.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use simd_aligned::{VecSimd, VecSimdView, arch::{f32x4, u8x16}};
+    ///
+    /// let v = VecSimd::<f32x4>::from_slice(&[1.0, 2.0, 3.0]);
+    ///
+    /// // Bytes that are 16 byte aligned, like those of a mapped file.
+    /// let mut bytes = VecSimd::<u8x16>::with(0, 80);
+    /// v.write_to(bytes.flat_mut())?;
+    ///
+    /// let view = VecSimdView::<f32x4>::from_bytes(bytes.flat())?;
+    /// view.verify()?;
+    ///
+    /// assert_eq!(view.flat(), &[1.0, 2.0, 3.0]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if the header is invalid or doesn't match `T`, if `bytes` are shorter or
+    /// longer than the header says, or if the data isn't aligned for `T`.
+    #[inline]
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, Error> {
+        let (header, header_bytes, data) = parse::<T>(bytes, LAYOUT_VEC)?;
+
+        Ok(Self {
+            vector: VecSimdRef::new(data, header.row_length)?,
+            padding: header.padding,
+            header: header_bytes,
+        })
+    }
+
+    /// Checks the checksum of the header and data, reading all bytes once.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ChecksumMismatch`] if the bytes differ from those written.
+    #[inline]
+    pub fn verify(&self) -> Result<(), Error> {
+        format::verify(self.header, packed_as_bytes(&self.vector[..]))
+    }
+}
+
+impl<T> VecSimdView<'_, T>
+where
+    T: Simd,
+{
+    /// Returns the [`Padding`] policy the vector was written with.
+    #[inline]
+    #[must_use]
+    pub const fn padding(&self) -> Padding<T::Element> {
+        self.padding
+    }
+}
+
+impl<'a, T> Deref for VecSimdView<'a, T>
+where
+    T: Simd,
+{
+    type Target = VecSimdRef<'a, T>;
+
+    #[inline]
+    fn deref(&self) -> &VecSimdRef<'a, T> {
+        &self.vector
+    }
+}
+
+/// A read-only [`MatSimd`](crate::MatSimd) borrowed from a file in the [binary format](crate::format), e.g., a memory-mapped one.
+///
+/// As with [`VecSimdView`], the bytes must be aligned for `T`. Provides the API of [`MatSimdRef`],
+/// which matches the read-only API of [`MatSimd`](crate::MatSimd), through `Deref`.
+#[derive(Debug)]
+pub struct MatSimdView<'a, T, A>
+where
+    T: Simd,
+    A: AccessStrategy,
+{
+    matrix: MatSimdRef<'a, T, A>,
+    padding: Padding<T::Element>,
+    header: &'a [u8; HEADER_SIZE],
+}
+
+impl<T, A> Clone for MatSimdView<'_, T, A>
+where
+    T: Simd,
+    A: AccessStrategy,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, A> Copy for MatSimdView<'_, T, A>
+where
+    T: Simd,
+    A: AccessStrategy,
+{
+}
+
+impl<T, A> MatSimdView<'_, T, A>
+where
+    T: Simd,
+    A: AccessStrategy,
+{
+    /// Returns the [`Padding`] policy the matrix was written with.
+    #[inline]
+    #[must_use]
+    pub const fn padding(&self) -> Padding<T::Element> {
+        self.padding
+    }
+}
+
+impl<T, A> MatSimdView<'_, T, A>
+where
+    T: Storable,
+    A: AccessStrategy,
+{
+    /// Checks the checksum of the header and data, reading all bytes once.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ChecksumMismatch`] if the bytes differ from those written.
+    #[inline]
+    pub fn verify(&self) -> Result<(), Error> {
+        format::verify(self.header, packed_as_bytes(self.matrix.data()))
+    }
+}
+
+impl<'a, T, A> Deref for MatSimdView<'a, T, A>
+where
+    T: Simd,
+    A: AccessStrategy,
+{
+    type Target = MatSimdRef<'a, T, A>;
+
+    #[inline]
+    fn deref(&self) -> &MatSimdRef<'a, T, A> {
+        &self.matrix
+    }
+}
+
+macro_rules! impl_mat_view {
+    ($strategy:ty, $layout:expr) => {
+        impl<'a, T> MatSimdView<'a, T, $strategy>
+        where
+            T: Storable,
+        {
+            /// Validates the header of `bytes` and borrows the matrix they hold.
+            ///
+            /// The data itself isn't read, so this is cheap even for large mapped files. Use
+            /// [`verify`](Self::verify) to also check the checksum.
+            ///
+            /// # Errors
+            ///
+            /// Returns an [`Error`] if the header is invalid or doesn't match `T` or the access strategy,
+            /// if `bytes` are shorter or longer than the header says, or if the data isn't aligned for `T`.
+            #[inline]
+            pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, Error> {
+                let (header, header_bytes, data) = parse::<T>(bytes, $layout)?;
+
+                Ok(Self {
+                    matrix: MatSimdRef::from_parts(data, header.rows, header.row_length, header.vectors_per_row),
+                    padding: header.padding,
+                    header: header_bytes,
+                })
+            }
+        }
+    };
+}
+
+impl_mat_view!(Rows, LAYOUT_ROWS);
+impl_mat_view!(Columns, LAYOUT_COLUMNS);
+
+/// A read-only memory-mapped file, available with the `mmap` feature.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use simd_aligned::{MappedFile, MatSimdView, arch::f32x8, Rows};
+///
+/// // SAFETY: Nobody modifies the file while it is mapped.
+/// let file = unsafe { MappedFile::open("matrix.bin")? };
+/// let m = MatSimdView::<f32x8, Rows>::from_bytes(&file)?;
+/// m.verify()?;
+///
+/// let _ = m.row(0);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "mmap")]
+#[derive(Debug)]
+pub struct MappedFile {
+    map: memmap2::Mmap,
+}
+
+#[cfg(feature = "mmap")]
+impl MappedFile {
+    /// Maps the file at `path` into memory.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from opening or mapping the file.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated while it is mapped, by this or any other process.
+    /// Otherwise, the views borrowed from it could change or become invalid while in use.
+    #[inline]
+    pub unsafe fn open(path: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
+        let file = std::fs::File::open(path)?;
+
+        // SAFETY: Upheld by the caller.
+        let map = unsafe { memmap2::Mmap::map(&file)? };
+
+        Ok(Self { map })
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl Deref for MappedFile {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        &self.map
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{MatSimdView, VecSimdView};
+    use crate::{
+        arch::{f32x8, i32x4},
+        format::{packed_as_bytes, packed_as_bytes_mut, HEADER_SIZE},
+        Columns, Error, MatSimd, Padding, Rows, VecSimd,
+    };
+
+    /// Copies `bytes` into storage aligned for `f32x8`, starting `offset` bytes in.
+    fn aligned(bytes: &[u8], offset: usize) -> Vec<f32x8> {
+        let mut storage = vec![f32x8::default(); (bytes.len() + offset).div_ceil(32)];
+        packed_as_bytes_mut(&mut storage)[offset..offset + bytes.len()].copy_from_slice(bytes);
+        storage
+    }
+
+    fn bytes_of(storage: &[f32x8], offset: usize, length: usize) -> &[u8] {
+        &packed_as_bytes(storage)[offset..offset + length]
+    }
+
+    #[test]
+    fn views() {
+        let mut v = VecSimd::<i32x4>::from_slice(&[1, 2, 3, 4, 5]);
+        let mut m = MatSimd::<f32x8, Rows>::with_dimension(3, 10);
+        let mut v_bytes = Vec::new();
+        let mut r_bytes = Vec::new();
+        let mut c_bytes = Vec::new();
+
+        v.set_padding(Padding::Value(-1));
+        m.row_as_flat_mut(2)[9] = 7.0;
+        v.write_to(&mut v_bytes).unwrap();
+        m.write_to(&mut r_bytes).unwrap();
+        m.to_columns().write_to(&mut c_bytes).unwrap();
+
+        let v_storage = aligned(&v_bytes, 0);
+        let r_storage = aligned(&r_bytes, 0);
+        let c_storage = aligned(&c_bytes, 0);
+        let v_view = VecSimdView::<i32x4>::from_bytes(bytes_of(&v_storage, 0, v_bytes.len())).unwrap();
+        let r_view = MatSimdView::<f32x8, Rows>::from_bytes(bytes_of(&r_storage, 0, r_bytes.len())).unwrap();
+        let c_view = MatSimdView::<f32x8, Columns>::from_bytes(bytes_of(&c_storage, 0, c_bytes.len())).unwrap();
+
+        assert_eq!(v_view.verify(), Ok(()));
+        assert_eq!(r_view.verify(), Ok(()));
+        assert_eq!(c_view.verify(), Ok(()));
+        assert_eq!(v_view.flat(), &[1, 2, 3, 4, 5]);
+        assert_eq!(v_view.len(), 5);
+        assert_eq!(v_view[1].to_array(), [5, -1, -1, -1]);
+        assert_eq!(v_view.simd_chunks().len(), 2);
+        assert_eq!(v_view.padding(), Padding::Value(-1));
+
+        assert_eq!(r_view.dimension(), (3, 10));
+        assert_eq!(r_view.row(2).len(), 2);
+        assert_eq!(r_view.row_as_flat(2)[9].to_bits(), 7.0_f32.to_bits());
+        assert_eq!(r_view.flat()[(2, 9)].to_bits(), 7.0_f32.to_bits());
+        assert_eq!(r_view.row_iter().len(), 3);
+        assert_eq!(r_view.row_iter_as_flat().map(<[f32]>::len).sum::<usize>(), 30);
+
+        assert_eq!(c_view.dimension(), (3, 10));
+        assert_eq!(c_view.column_as_flat(9)[2].to_bits(), 7.0_f32.to_bits());
+        assert_eq!(c_view.flat()[(2, 9)].to_bits(), 7.0_f32.to_bits());
+        assert_eq!(c_view.column_iter().len(), 10);
+    }
+
+    #[test]
+    fn validation() {
+        let mut bytes = Vec::new();
+        MatSimd::<f32x8, Rows>::with_dimension(2, 3).write_to(&mut bytes).unwrap();
+
+        let storage = aligned(&bytes, 4);
+        let misaligned = MatSimdView::<f32x8, Rows>::from_bytes(bytes_of(&storage, 4, bytes.len()));
+
+        let mut storage = aligned(&bytes, 0);
+        let truncated = MatSimdView::<f32x8, Rows>::from_bytes(bytes_of(&storage, 0, bytes.len() - 1));
+        let header_only = MatSimdView::<f32x8, Rows>::from_bytes(bytes_of(&storage, 0, HEADER_SIZE - 1));
+        let layout = MatSimdView::<f32x8, Columns>::from_bytes(bytes_of(&storage, 0, bytes.len()));
+
+        assert_eq!(misaligned.unwrap_err(), Error::Misaligned { alignment: 32 });
+        assert_eq!(truncated.unwrap_err(), Error::SizeMismatch { expected: 128, actual: 127 });
+        assert_eq!(header_only.unwrap_err(), Error::SizeMismatch { expected: 64, actual: 63 });
+        assert_eq!(layout.unwrap_err(), Error::InvalidHeader { reason: "layout mismatch" });
+
+        packed_as_bytes_mut(&mut storage)[HEADER_SIZE] ^= 1;
+        let corrupt = MatSimdView::<f32x8, Rows>::from_bytes(bytes_of(&storage, 0, bytes.len())).unwrap();
+
+        assert_eq!(corrupt.dimension(), (2, 3));
+        assert_eq!(corrupt.verify(), Err(Error::ChecksumMismatch));
+    }
+
+    #[test]
+    #[cfg(feature = "mmap")]
+    fn mapped_file() {
+        let path = std::env::temp_dir().join(format!("simd_aligned_view_{}.bin", std::process::id()));
+        let mut m = MatSimd::<f32x8, Rows>::with_dimension(4, 20);
+
+        m.row_as_flat_mut(3)[19] = 3.0;
+        m.write_to(std::fs::File::create(&path).unwrap()).unwrap();
+
+        // SAFETY: The file is private to this test.
+        let file = unsafe { super::MappedFile::open(&path).unwrap() };
+        let view = MatSimdView::<f32x8, Rows>::from_bytes(&file).unwrap();
+
+        assert_eq!(view.dimension(), (4, 20));
+        assert_eq!(view.row_as_flat(3)[19].to_bits(), 3.0_f32.to_bits());
+
+        drop(file);
+        std::fs::remove_file(path).unwrap();
+    }
+}